@@ -0,0 +1,554 @@
+use alloc::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    vec,
+    vec::Vec,
+};
+use core::slice;
+
+use serde::{
+    de::{self, DeserializeOwned},
+    Deserialize, Serialize,
+};
+use serde_json::Value;
+
+use crate::{Error, Notification, Request, Response};
+
+/// To send several Request objects at the same time, the Client MAY send an
+/// Array filled with Request objects.
+///
+/// The Server should respond with an Array containing the corresponding
+/// Response objects, after all of the batch Request objects have been
+/// processed. A Response object SHOULD exist for each Request object, except
+/// that there SHOULD NOT be any Response objects for notifications. The Server
+/// MAY process a batch rpc call as a set of concurrent tasks, processing them
+/// in any order and with any width of parallelism.
+///
+/// The Response objects being returned from a batch call MAY be returned in
+/// any order within the Array. The Client SHOULD match contexts between the
+/// set of Request objects and the resulting set of Response objects based on
+/// the id member within each Object.
+///
+/// If the batch rpc call itself fails to be recognized as an valid JSON or as
+/// an Array with at least one value, the response from the Server MUST be a
+/// single Response object. If there are no Response objects contained within
+/// the Response array as it is to be sent to the client, the server MUST NOT
+/// return an empty Array and should return nothing at all.
+///
+/// Deserializing an empty Array fails.
+#[derive(Serialize, PartialEq, Eq, Hash, Debug, Clone)]
+#[serde(transparent)]
+pub struct Batch<T>(pub Vec<T>);
+
+impl<T> Batch<T> {
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
+    /// Append the elements of `other`, e.g. to combine batches of
+    /// notifications.
+    pub fn concat(mut self, other: Self) -> Self {
+        self.0.extend(other.0);
+        self
+    }
+
+    /// Process a batch call, collecting the Responses returned by `handler`.
+    ///
+    /// `handler` returns [`None`] for notifications. If there are no Response
+    /// objects, the server MUST NOT return an empty Array and should return
+    /// nothing at all, so [`None`] is returned.
+    pub fn process<R, E, I, F>(self, handler: F) -> Option<Batch<Response<R, E, I>>>
+    where
+        F: FnMut(T) -> Option<Response<R, E, I>>,
+    {
+        let responses: Vec<_> = self.0.into_iter().filter_map(handler).collect();
+
+        (!responses.is_empty()).then_some(Batch(responses))
+    }
+}
+
+impl<'a, P> Batch<Notification<'a, P>> {
+    /// Append a Notification, so that many notifications can be sent in a
+    /// single batch.
+    ///
+    /// ```
+    /// use jsonrpc_types::Batch;
+    ///
+    /// let batch = Batch::default().push("a", [1]).push("b", [2]);
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_string(&batch).unwrap(),
+    ///     r#"[{"jsonrpc":"2.0","method":"a","params":[1]},{"jsonrpc":"2.0","method":"b","params":[2]}]"#
+    /// );
+    /// ```
+    pub fn push(mut self, method: impl Into<Cow<'a, str>>, params: P) -> Self {
+        self.0.push(Notification::new(method, params));
+        self
+    }
+}
+
+impl<R, E, I: Ord + Clone> Batch<Response<R, E, I>> {
+    /// Index the responses by their **id**.
+    ///
+    /// Responses with a Null id (or a repeated id) can't be correlated, and
+    /// are returned separately in their original order.
+    #[allow(clippy::type_complexity)]
+    pub fn index_by_id(self) -> (BTreeMap<I, Response<R, E, I>>, Vec<Response<R, E, I>>) {
+        let mut by_id = BTreeMap::new();
+        let mut uncorrelated = Vec::new();
+
+        for response in self.0 {
+            match &response.id {
+                Some(id) if !by_id.contains_key(id) => {
+                    by_id.insert(id.clone(), response);
+                }
+                _ => uncorrelated.push(response),
+            }
+        }
+
+        (by_id, uncorrelated)
+    }
+}
+
+impl<'a, P, I: Ord + Clone> Batch<Request<'a, P, I>> {
+    /// Append the requests of `other`, dropping those with an **id** already
+    /// in the batch (the first one is kept).
+    ///
+    /// Requests without an **id** are notifications, and are never dropped.
+    pub fn merge(self, other: Self) -> Self {
+        let mut ids = BTreeSet::new();
+
+        self.0
+            .into_iter()
+            .chain(other.0)
+            .filter(|request| match &request.id {
+                Some(id) => ids.insert(id.clone()),
+                None => true,
+            })
+            .collect()
+    }
+
+    /// Match each request with its response by **id**, in request order.
+    ///
+    /// Notifications are skipped, since they have no response. Responses
+    /// matching no request (e.g. with a Null id) are returned separately, in
+    /// their original order.
+    #[allow(clippy::type_complexity)]
+    pub fn correlate<R, E>(
+        self,
+        responses: Batch<Response<R, E, I>>,
+    ) -> (
+        Vec<(Request<'a, P, I>, Option<Response<R, E, I>>)>,
+        Vec<Response<R, E, I>>,
+    ) {
+        let mut pairs: Vec<_> = self
+            .0
+            .into_iter()
+            .filter(|request| request.id.is_some())
+            .map(|request| (request, None))
+            .collect();
+
+        // a repeated request id is only matched for its first request
+        let mut positions = BTreeMap::new();
+        for (position, (request, _)) in pairs.iter().enumerate() {
+            if let Some(id) = &request.id {
+                positions.entry(id.clone()).or_insert(position);
+            }
+        }
+
+        let mut uncorrelated = Vec::new();
+        for response in responses.0 {
+            match response.id.as_ref().and_then(|id| positions.remove(id)) {
+                Some(position) => pairs[position].1 = Some(response),
+                None => uncorrelated.push(response),
+            }
+        }
+
+        (pairs, uncorrelated)
+    }
+}
+
+impl Batch<Value> {
+    /// Split a batch call into its requests and notifications, preserving
+    /// their order.
+    ///
+    /// Objects with an **id** member are requests, objects without one are
+    /// notifications. Elements which are neither are returned separately
+    /// with their index in the batch, so that the valid ones can still be
+    /// processed.
+    #[allow(clippy::type_complexity)]
+    pub fn partition<P, I>(
+        self,
+    ) -> (
+        Vec<Request<'static, P, I>>,
+        Vec<Notification<'static, P>>,
+        Vec<(usize, Error)>,
+    )
+    where
+        P: DeserializeOwned,
+        I: DeserializeOwned,
+    {
+        let mut requests = Vec::new();
+        let mut notifications = Vec::new();
+        let mut invalid = Vec::new();
+
+        for (index, value) in self.0.into_iter().enumerate() {
+            let is_request = value.as_object().is_some_and(|o| o.contains_key("id"));
+            let result = if is_request {
+                Request::try_from(value).map(|r| requests.push(r))
+            } else {
+                Notification::try_from(value).map(|n| notifications.push(n))
+            };
+
+            if let Err(error) = result {
+                invalid.push((index, error));
+            }
+        }
+
+        (requests, notifications, invalid)
+    }
+}
+
+impl<T> Default for Batch<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> From<Vec<T>> for Batch<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self(items)
+    }
+}
+
+impl<T> From<Batch<T>> for Vec<T> {
+    fn from(batch: Batch<T>) -> Self {
+        batch.0
+    }
+}
+
+impl<T> IntoIterator for Batch<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'b, T> IntoIterator for &'b Batch<T> {
+    type Item = &'b T;
+    type IntoIter = slice::Iter<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'b, T> IntoIterator for &'b mut Batch<T> {
+    type Item = &'b mut T;
+    type IntoIter = slice::IterMut<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Collecting an empty iterator is allowed, only deserializing an empty
+/// batch fails.
+impl<T> FromIterator<T> for Batch<T> {
+    fn from_iter<It: IntoIterator<Item = T>>(iter: It) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<T> for Batch<T> {
+    fn extend<It: IntoIterator<Item = T>>(&mut self, iter: It) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Batch<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let items = Vec::deserialize(deserializer)?;
+
+        if items.is_empty() {
+            return Err(de::Error::custom(Error::EmptyBatch));
+        }
+
+        Ok(Self(items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        test_utils::{snapshot, Params},
+        Id, Message, ResponseContent, ResponseError,
+    };
+
+    #[test]
+    fn test_batch_serde() {
+        snapshot!(Batch(vec![
+            Request::new("method", vec![0, 1], Some(Id::from(1))),
+            Request::new("method", vec![0, 1], None),
+        ]));
+        snapshot!(Batch(vec![
+            Response::new(
+                ResponseContent::<_, ()>::Result(Params { p0: 0, p1: 1 }),
+                Some(Id::from(1))
+            ),
+            Response::new(
+                ResponseContent::Error(ResponseError::method_not_found()),
+                Some(2.into())
+            ),
+        ]));
+    }
+
+    #[test]
+    fn test_batch_empty() {
+        assert!(serde_json::from_str::<Batch<Request<()>>>("[]").is_err());
+    }
+
+    #[test]
+    fn test_batch_iter() {
+        let mut batch = Batch::from(vec![
+            Request::new("a", (), Some(Id::from(1))),
+            Request::new("b", (), Some(2.into())),
+        ]);
+
+        for request in batch.iter_mut() {
+            request.id = None;
+        }
+
+        assert_eq!(
+            batch.iter().map(|r| r.method.as_ref()).collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+        assert!(Vec::from(batch).iter().all(|r| r.id.is_none()));
+    }
+
+    #[test]
+    fn test_batch_iterators() {
+        let mut batch: Batch<_> = ["a", "b"]
+            .into_iter()
+            .map(|method| Request::new(method, (), Some(Id::from(1))))
+            .collect();
+        batch.extend([Request::new("c", (), None)]);
+
+        for request in &mut batch {
+            request.id = None;
+        }
+
+        assert_eq!(
+            (&batch)
+                .into_iter()
+                .map(|r| r.method.as_ref())
+                .collect::<Vec<_>>(),
+            ["a", "b", "c"]
+        );
+        assert!(batch.into_iter().all(|r| r.id.is_none()));
+        assert_eq!(
+            core::iter::empty::<Request<()>>().collect::<Batch<_>>(),
+            Batch(vec![])
+        );
+    }
+
+    #[test]
+    fn test_batch_borrowed() {
+        let requests = [
+            Request::new("a", vec![0], Some(Id::from(1))),
+            Request::new("b", vec![1], Some(Id::from(2))),
+        ];
+        let batch: Batch<&Request<_>> = requests.iter().collect();
+
+        assert_eq!(
+            serde_json::to_string(&batch).unwrap(),
+            serde_json::to_string(&Batch(requests.to_vec())).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_string(&batch).unwrap(),
+            serde_json::to_string(&[&requests[0], &requests[1]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_batch_concat() {
+        let batch = Batch::default()
+            .push("a", ())
+            .concat(Batch::default().push("a", ()).push("b", ()));
+
+        assert_eq!(
+            batch.iter().map(|n| n.method.as_ref()).collect::<Vec<_>>(),
+            ["a", "a", "b"]
+        );
+    }
+
+    #[test]
+    fn test_batch_merge() {
+        let first = Batch(vec![
+            Request::new("a", 0, Some(Id::from(1))),
+            Request::new("notify", 0, None),
+            Request::new("b", 0, Some(Id::from(2))),
+            Request::new("a", 1, Some(Id::from(1))),
+        ]);
+        let second = Batch(vec![
+            Request::new("c", 1, Some(Id::from(2))),
+            Request::new("notify", 1, None),
+            Request::new("d", 1, Some(Id::from(3))),
+            Request::new("e", 1, Some(Id::Other(Value::Bool(true)))),
+            Request::new("f", 1, Some(Id::Other(Value::Bool(true)))),
+        ]);
+
+        assert_eq!(
+            first.merge(second),
+            Batch(vec![
+                Request::new("a", 0, Some(Id::from(1))),
+                Request::new("notify", 0, None),
+                Request::new("b", 0, Some(Id::from(2))),
+                Request::new("notify", 1, None),
+                Request::new("d", 1, Some(Id::from(3))),
+                Request::new("e", 1, Some(Id::Other(Value::Bool(true)))),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_batch_process() {
+        type TestMessage = Message<'static, (), i32, ()>;
+
+        fn handle(message: TestMessage) -> Option<Response<i32, ()>> {
+            match message {
+                Message::Request(request) => Some(request.success_response(1)),
+                _ => None,
+            }
+        }
+
+        let request = || Message::Request(Request::new("r", (), Some(Id::from(1))));
+        let notification = || Message::Notification(Notification::new("n", ()));
+
+        assert_eq!(
+            Batch(vec![notification(), notification()]).process(handle),
+            None
+        );
+        assert_eq!(
+            Batch(vec![request(), notification(), request()])
+                .process(handle)
+                .map(|b| b.0.len()),
+            Some(2)
+        );
+        assert_eq!(
+            Batch(vec![request(), request()]).process(handle),
+            Some(Batch(vec![
+                Response::new(ResponseContent::Result(1), Some(Id::from(1))),
+                Response::new(ResponseContent::Result(1), Some(Id::from(1))),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_batch_push_notifications() {
+        snapshot!(Batch::default()
+            .push("a", Params { p0: 0, p1: 1 })
+            .push("b", Params { p0: 2, p1: 3 })
+            .push("c", Params { p0: 4, p1: 5 }));
+    }
+
+    #[test]
+    fn test_batch_partition() {
+        let batch: Batch<Value> = serde_json::from_str(
+            r#"[
+                {"jsonrpc":"2.0","method":"a","params":[1],"id":1},
+                {"jsonrpc":"2.0","method":"notify","params":[2]},
+                {"foo":"boo"},
+                {"jsonrpc":"2.0","method":"b","params":[3],"id":"2"},
+                1,
+                {"jsonrpc":"2.0","method":"notify_again","params":[4]}
+            ]"#,
+        )
+        .unwrap();
+
+        let (requests, notifications, invalid) = batch.partition::<Vec<i32>, Id>();
+
+        assert_eq!(
+            requests,
+            [
+                Request::new("a", vec![1], Some(Id::from(1))),
+                Request::new("b", vec![3], Some(Id::from("2"))),
+            ]
+        );
+        assert_eq!(
+            notifications,
+            [
+                Notification::new("notify", vec![2]),
+                Notification::new("notify_again", vec![4]),
+            ]
+        );
+        assert_eq!(
+            invalid.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+            [2, 4]
+        );
+    }
+
+    #[test]
+    fn test_batch_index_by_id() {
+        let null_id = Response::new(ResponseContent::Error(ResponseError::parse_error()), None);
+        let batch = Batch(vec![
+            Response::new(ResponseContent::<_, ()>::Result(2), Some(Id::from(2))),
+            null_id.clone(),
+            Response::new(ResponseContent::Result(1), Some(Id::from(1))),
+        ]);
+
+        let (by_id, uncorrelated) = batch.index_by_id();
+
+        assert_eq!(by_id[&Id::from(1)].result(), Some(&1));
+        assert_eq!(by_id[&Id::from(2)].result(), Some(&2));
+        assert_eq!(uncorrelated, [null_id]);
+    }
+
+    #[test]
+    fn test_batch_correlate() {
+        let requests = Batch(vec![
+            Request::new("a", (), Some(Id::from(1))),
+            Request::new("notify", (), None),
+            Request::new("b", (), Some(Id::from(2))),
+            Request::new("c", (), Some(Id::from(3))),
+        ]);
+        let null_id = Response::new(
+            ResponseContent::Error(ResponseError::invalid_request()),
+            None,
+        );
+        let unknown_id = Response::new(ResponseContent::Result("d"), Some(Id::from(4)));
+        let repeated_id = Response::new(ResponseContent::Result("a2"), Some(Id::from(1)));
+        let responses = Batch(vec![
+            Response::new(ResponseContent::<_, ()>::Result("b"), Some(Id::from(2))),
+            unknown_id.clone(),
+            null_id.clone(),
+            Response::new(ResponseContent::Result("a"), Some(Id::from(1))),
+            repeated_id.clone(),
+        ]);
+
+        let (pairs, uncorrelated) = requests.correlate(responses);
+
+        assert_eq!(
+            pairs
+                .iter()
+                .map(|(request, response)| (
+                    request.method.as_ref(),
+                    response.as_ref().and_then(Response::result).copied()
+                ))
+                .collect::<Vec<_>>(),
+            [("a", Some("a")), ("b", Some("b")), ("c", None)]
+        );
+        assert_eq!(uncorrelated, [unknown_id, null_id, repeated_id]);
+    }
+}
@@ -0,0 +1,513 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{mem, ops::Range, str};
+
+#[cfg(feature = "tokio-util")]
+use bytes::{Buf, BytesMut};
+use serde::{de::DeserializeOwned, Serialize};
+#[cfg(feature = "tokio-util")]
+use serde_json::Value;
+
+use crate::{Error, Message};
+
+/// How messages are delimited in a byte stream.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Framing {
+    /// Each message is preceded by a `Content-Length` header, as in the
+    /// Language Server Protocol:
+    ///
+    /// ```text
+    /// Content-Length: 37\r\n
+    /// \r\n
+    /// {"jsonrpc":"2.0","method":"m","id":1}
+    /// ```
+    ContentLength,
+    /// Each message is a single line, terminated by `\n`.
+    Lines,
+}
+
+/// Frames JSON-RPC messages in a byte stream.
+///
+/// The codec doesn't perform any IO: [`JsonRpcCodec::decode`] consumes
+/// complete frames from a buffer that bytes are read into, and
+/// [`JsonRpcCodec::encode`] appends frames to a buffer that is then written.
+///
+/// With the `tokio-util` feature, it also implements `tokio_util`'s
+/// `Decoder` and `Encoder`, to be used with a `Framed` transport. Since the
+/// `Decoder` can't be generic over the decoded types, it yields messages with
+/// [`Value`] members, as [`parse_message`](crate::parse_message) does.
+///
+/// ```
+/// use jsonrpc_types::{Framing, Id, JsonRpcCodec, Message, Request};
+///
+/// let mut codec = JsonRpcCodec::new(Framing::Lines);
+/// let mut buf = Vec::new();
+///
+/// codec.encode(&Request::new("m", [1], None::<Id>), &mut buf).unwrap();
+/// assert_eq!(buf, b"{\"jsonrpc\":\"2.0\",\"method\":\"m\",\"params\":[1]}\n");
+///
+/// let message: Option<Message<[i32; 1], (), ()>> = codec.decode(&mut buf).unwrap();
+/// assert!(matches!(message, Some(Message::Notification(_))));
+/// assert!(buf.is_empty());
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct JsonRpcCodec {
+    framing: Framing,
+    max_frame_len: usize,
+    /// The bytes of an oversized `Content-Length` body still to discard.
+    skip: usize,
+    /// Whether the rest of an oversized line is being discarded.
+    discarding_line: bool,
+}
+
+const CONTENT_LENGTH: &str = "Content-Length";
+const HEADER_END: &[u8] = b"\r\n\r\n";
+/// The maximum length of a block of headers, the **Content-Length** header
+/// being the only one needed.
+const MAX_HEADERS_LEN: usize = 4096;
+
+impl JsonRpcCodec {
+    /// The default [`JsonRpcCodec::max_frame_len`], 8 MiB.
+    pub const DEFAULT_MAX_FRAME_LEN: usize = 8 * 1024 * 1024;
+
+    pub fn new(framing: Framing) -> Self {
+        Self {
+            framing,
+            max_frame_len: Self::DEFAULT_MAX_FRAME_LEN,
+            skip: 0,
+            discarding_line: false,
+        }
+    }
+
+    /// Limit the length of a message, so that a peer can't make the codec
+    /// buffer an unbounded amount of bytes.
+    ///
+    /// A longer message fails to decode with [`Error::FrameTooLarge`], and
+    /// is discarded as its bytes arrive.
+    pub fn with_max_frame_len(mut self, max_frame_len: usize) -> Self {
+        self.max_frame_len = max_frame_len;
+        self
+    }
+
+    pub fn framing(&self) -> Framing {
+        self.framing
+    }
+
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    /// Decode the first message in `buf`, removing its frame.
+    ///
+    /// Returns `None` if `buf` doesn't contain a complete frame yet. A frame
+    /// which isn't a valid message is still removed, so that decoding can
+    /// continue with the next frame.
+    pub fn decode<P, R, E, I>(
+        &mut self,
+        buf: &mut Vec<u8>,
+    ) -> Result<Option<Message<'static, P, R, E, I>>, Error>
+    where
+        P: DeserializeOwned,
+        R: DeserializeOwned,
+        E: DeserializeOwned,
+        I: DeserializeOwned,
+    {
+        let (consumed, message) = self.decode_slice(buf);
+        buf.drain(..consumed);
+        message
+    }
+
+    /// Encode `item` (e.g. a [`Request`](crate::Request),
+    /// [`Notification`](crate::Notification) or
+    /// [`Response`](crate::Response)) as a frame at the end of `buf`.
+    pub fn encode<T: Serialize + ?Sized>(
+        &mut self,
+        item: &T,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), Error> {
+        let body = serde_json::to_vec(item)?;
+
+        match self.framing {
+            Framing::ContentLength => {
+                buf.extend_from_slice(
+                    format!("{CONTENT_LENGTH}: {}\r\n\r\n", body.len()).as_bytes(),
+                );
+                buf.extend_from_slice(&body);
+            }
+            Framing::Lines => {
+                buf.extend_from_slice(&body);
+                buf.push(b'\n');
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decode the first message in `buf`, along with the number of bytes to
+    /// remove from its front.
+    fn decode_slice<M: DeserializeOwned>(
+        &mut self,
+        buf: &[u8],
+    ) -> (usize, Result<Option<M>, Error>) {
+        let (consumed, frame) = match self.framing {
+            Framing::ContentLength => self.scan_content_length(buf),
+            Framing::Lines => self.scan_line(buf),
+        };
+
+        let message = frame.and_then(|frame| match frame {
+            Some(frame) => Ok(Some(serde_json::from_slice(&buf[frame])?)),
+            None => Ok(None),
+        });
+
+        (consumed, message)
+    }
+
+    /// Find the first non-empty line in `buf`.
+    fn scan_line(&mut self, buf: &[u8]) -> (usize, Result<Option<Range<usize>>, Error>) {
+        let mut start = 0;
+
+        while let Some(end) = buf[start..].iter().position(|&b| b == b'\n') {
+            let mut line = start..start + end;
+            start = line.end + 1;
+
+            if mem::take(&mut self.discarding_line) {
+                continue;
+            }
+            if buf[line.clone()].last() == Some(&b'\r') {
+                line.end -= 1;
+            }
+            if line.len() > self.max_frame_len {
+                return (start, Err(self.frame_too_large(line.len())));
+            }
+            if !buf[line.clone()].iter().all(u8::is_ascii_whitespace) {
+                return (start, Ok(Some(line)));
+            }
+        }
+
+        // the incomplete line is already too long, discard it until its end
+        let pending = buf.len() - start;
+        if self.discarding_line {
+            (buf.len(), Ok(None))
+        } else if pending > self.max_frame_len {
+            self.discarding_line = true;
+            (buf.len(), Err(self.frame_too_large(pending)))
+        } else {
+            (start, Ok(None))
+        }
+    }
+
+    /// Find the first `Content-Length` framed body in `buf`.
+    fn scan_content_length(&mut self, buf: &[u8]) -> (usize, Result<Option<Range<usize>>, Error>) {
+        // the rest of an oversized body
+        let start = self.skip.min(buf.len());
+        self.skip -= start;
+        if self.skip > 0 {
+            return (start, Ok(None));
+        }
+
+        let Some(header_len) = buf[start..]
+            .windows(HEADER_END.len())
+            .position(|w| w == HEADER_END)
+            .map(|i| i + HEADER_END.len())
+        else {
+            let pending = buf.len() - start;
+            if pending > MAX_HEADERS_LEN {
+                let header = String::from_utf8_lossy(&buf[start..start + 64]);
+                return (buf.len(), Err(invalid_header(&header)));
+            }
+            return (start, Ok(None));
+        };
+        let body = start + header_len;
+
+        let content_length = match content_length(&buf[start..body - HEADER_END.len()]) {
+            Ok(content_length) => content_length,
+            Err(e) => return (body, Err(e)),
+        };
+
+        if content_length > self.max_frame_len {
+            let available = content_length.min(buf.len() - body);
+            self.skip = content_length - available;
+            return (body + available, Err(self.frame_too_large(content_length)));
+        }
+
+        if buf.len() < body + content_length {
+            return (start, Ok(None));
+        }
+
+        (body + content_length, Ok(Some(body..body + content_length)))
+    }
+
+    fn frame_too_large(&self, len: usize) -> Error {
+        Error::FrameTooLarge {
+            len,
+            max: self.max_frame_len,
+        }
+    }
+}
+
+impl Default for JsonRpcCodec {
+    fn default() -> Self {
+        Self::new(Framing::Lines)
+    }
+}
+
+#[cfg(feature = "tokio-util")]
+impl tokio_util::codec::Decoder for JsonRpcCodec {
+    type Item = Message<'static, Value, Value, Value>;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let (consumed, message) = self.decode_slice(src);
+        src.advance(consumed);
+        message
+    }
+}
+
+#[cfg(feature = "tokio-util")]
+impl<T: Serialize> tokio_util::codec::Encoder<T> for JsonRpcCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        let mut buf = Vec::new();
+        JsonRpcCodec::encode(self, &item, &mut buf)?;
+        dst.extend_from_slice(&buf);
+        Ok(())
+    }
+}
+
+fn invalid_header(header: &str) -> Error {
+    Error::InvalidHeader {
+        header: header.to_string(),
+    }
+}
+
+/// Find the `Content-Length` in a header block, ignoring other headers.
+fn content_length(headers: &[u8]) -> Result<usize, Error> {
+    let headers =
+        str::from_utf8(headers).map_err(|_| invalid_header(&String::from_utf8_lossy(headers)))?;
+    let mut content_length = None;
+
+    for header in headers.split("\r\n") {
+        let (name, value) = header
+            .split_once(':')
+            .ok_or_else(|| invalid_header(header))?;
+        if name.trim().eq_ignore_ascii_case(CONTENT_LENGTH) {
+            content_length = Some(value.trim().parse().map_err(|_| invalid_header(header))?);
+        }
+    }
+
+    content_length.ok_or_else(|| invalid_header(headers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Id, Notification, Request, Response, ResponseContent};
+
+    type TestMessage = Message<'static, Vec<i32>, i32, ()>;
+
+    #[test]
+    fn test_codec_content_length() {
+        let mut codec = JsonRpcCodec::new(Framing::ContentLength);
+        let body = r#"{"jsonrpc":"2.0","method":"m","params":[1],"id":1}"#;
+        let mut buf = format!(
+            "Content-Length: {}\r\nContent-Type: application/vscode-jsonrpc; charset=utf-8\r\n\r\n{body}",
+            body.len()
+        )
+        .into_bytes();
+        let rest = buf.split_off(buf.len() - 5);
+
+        assert!(codec
+            .decode::<Vec<i32>, i32, (), Id>(&mut buf)
+            .unwrap()
+            .is_none());
+
+        buf.extend(rest);
+        assert_eq!(
+            codec.decode::<_, _, _, Id>(&mut buf).unwrap(),
+            Some(TestMessage::Request(Request::new(
+                "m",
+                vec![1],
+                Some(Id::from(1))
+            )))
+        );
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_codec_content_length_invalid() {
+        let mut codec = JsonRpcCodec::new(Framing::ContentLength);
+
+        for header in ["Content-Length: abc", "Content-Type: json", "garbage"] {
+            let mut buf = format!("{header}\r\n\r\n{{}}").into_bytes();
+
+            assert!(matches!(
+                codec.decode::<Vec<i32>, i32, (), Id>(&mut buf),
+                Err(Error::InvalidHeader { .. })
+            ));
+            assert_eq!(buf, b"{}");
+        }
+    }
+
+    #[test]
+    fn test_codec_content_length_too_large() {
+        let mut codec = JsonRpcCodec::new(Framing::ContentLength).with_max_frame_len(10);
+        let body = r#"{"jsonrpc":"2.0","method":"m","params":[1]}"#;
+        let mut buf = format!("Content-Length: {}\r\n\r\n{}", body.len(), &body[..20]).into_bytes();
+
+        assert!(matches!(
+            codec.decode::<Vec<i32>, i32, (), Id>(&mut buf),
+            Err(Error::FrameTooLarge { len, max: 10 }) if len == body.len()
+        ));
+        assert!(buf.is_empty());
+
+        // the rest of the body is discarded, the next frame is decoded
+        buf.extend_from_slice(&body.as_bytes()[20..]);
+        buf.extend_from_slice(b"Content-Length: 3\r\n\r\n[1");
+        assert!(codec
+            .decode::<Vec<i32>, i32, (), Id>(&mut buf)
+            .unwrap()
+            .is_none());
+        assert_eq!(buf, b"Content-Length: 3\r\n\r\n[1");
+
+        buf.extend_from_slice(b"]");
+        assert!(matches!(
+            codec.decode::<Vec<i32>, i32, (), Id>(&mut buf),
+            Err(Error::Json(_))
+        ));
+        assert!(buf.is_empty());
+
+        let mut buf = vec![b'a'; MAX_HEADERS_LEN + 1];
+        assert!(matches!(
+            codec.decode::<Vec<i32>, i32, (), Id>(&mut buf),
+            Err(Error::InvalidHeader { .. })
+        ));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_codec_lines_too_large() {
+        let mut codec = JsonRpcCodec::new(Framing::Lines).with_max_frame_len(50);
+        let line = r#"{"jsonrpc":"2.0","method":"m","params":[1]}"#;
+        let long_line = r#"{"jsonrpc":"2.0","method":"m","params":[1, 2, 3, 4, 5, 6]}"#;
+        let mut buf = format!("{long_line}\r\n{line}\n{long_line}").into_bytes();
+
+        assert!(matches!(
+            codec.decode::<Vec<i32>, i32, (), Id>(&mut buf),
+            Err(Error::FrameTooLarge { len, max: 50 }) if len == long_line.len()
+        ));
+        assert_eq!(
+            codec.decode::<_, _, _, Id>(&mut buf).unwrap(),
+            Some(TestMessage::Notification(Notification::new("m", vec![1])))
+        );
+
+        // an incomplete line is discarded until its end
+        assert!(matches!(
+            codec.decode::<Vec<i32>, i32, (), Id>(&mut buf),
+            Err(Error::FrameTooLarge { .. })
+        ));
+        assert!(buf.is_empty());
+
+        buf.extend_from_slice(format!("...]}}\n{line}\n").as_bytes());
+        assert_eq!(
+            codec.decode::<_, _, _, Id>(&mut buf).unwrap(),
+            Some(TestMessage::Notification(Notification::new("m", vec![1])))
+        );
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_codec_lines() {
+        let mut codec = JsonRpcCodec::new(Framing::Lines);
+        let mut buf = b"{\"jsonrpc\":\"2.0\",\"method\":\"n\",\"params\":[2]}\r\n\n{\"jsonrpc\":\"2.0\",\"result\":3"
+            .to_vec();
+
+        assert_eq!(
+            codec.decode::<_, _, _, Id>(&mut buf).unwrap(),
+            Some(TestMessage::Notification(Notification::new("n", vec![2])))
+        );
+        assert!(codec
+            .decode::<Vec<i32>, i32, (), Id>(&mut buf)
+            .unwrap()
+            .is_none());
+
+        buf.extend_from_slice(b",\"id\":1}\n");
+        assert_eq!(
+            codec.decode::<_, _, _, Id>(&mut buf).unwrap(),
+            Some(TestMessage::Response(Response::new(
+                ResponseContent::Result(3),
+                Some(Id::from(1))
+            )))
+        );
+        assert!(buf.is_empty());
+    }
+
+    #[cfg(feature = "tokio-util")]
+    #[test]
+    fn test_codec_tokio_util() {
+        use tokio_util::codec::{Decoder, Encoder};
+
+        for framing in [Framing::ContentLength, Framing::Lines] {
+            let mut codec = JsonRpcCodec::new(framing);
+            let mut buf = BytesMut::new();
+
+            Encoder::encode(
+                &mut codec,
+                Request::new("m", vec![1], Some(Id::from(1))),
+                &mut buf,
+            )
+            .unwrap();
+            Encoder::encode(&mut codec, &Notification::new("n", vec![2]), &mut buf).unwrap();
+
+            let mut rest = buf.split_off(buf.len() - 3);
+            assert!(matches!(
+                Decoder::decode(&mut codec, &mut buf).unwrap(),
+                Some(Message::Request(request)) if request.params == serde_json::json!([1])
+            ));
+            assert!(Decoder::decode(&mut codec, &mut buf).unwrap().is_none());
+
+            buf.unsplit(rest.split());
+            assert!(matches!(
+                Decoder::decode(&mut codec, &mut buf).unwrap(),
+                Some(Message::Notification(notification)) if notification.method == "n"
+            ));
+            assert!(buf.is_empty());
+        }
+
+        let mut codec = JsonRpcCodec::new(Framing::Lines).with_max_frame_len(4);
+        let mut buf = BytesMut::from("[1, 2]\n");
+        assert!(matches!(
+            Decoder::decode(&mut codec, &mut buf),
+            Err(Error::FrameTooLarge { len: 6, max: 4 })
+        ));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_codec_round_trip() {
+        for framing in [Framing::ContentLength, Framing::Lines] {
+            let mut codec = JsonRpcCodec::new(framing);
+            let mut buf = Vec::new();
+            let request = Request::new("m", vec![1], Some(Id::from(1)));
+
+            codec.encode(&request, &mut buf).unwrap();
+            codec
+                .encode(&Notification::new("n", vec![2]), &mut buf)
+                .unwrap();
+
+            assert_eq!(
+                codec.decode::<_, _, _, Id>(&mut buf).unwrap(),
+                Some(TestMessage::Request(request))
+            );
+            assert_eq!(
+                codec.decode::<_, _, _, Id>(&mut buf).unwrap(),
+                Some(TestMessage::Notification(Notification::new("n", vec![2])))
+            );
+            assert!(buf.is_empty());
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! The examples of section 7 of the specification.
+//!
+//! Each example is parsed and answered by a small server, and both the call
+//! and the reply are compared to the specification modulo whitespace.
+
+use serde_json::Value;
+
+use crate::{Batch, Error, Message, Params, Request, Response, ResponseError, Router};
+
+type SpecMessage = Message<'static, Params, Value, ()>;
+type SpecResponse = Response<Value, ()>;
+
+fn json(s: &str) -> Value {
+    serde_json::from_str(s).unwrap()
+}
+
+fn router() -> Router<Params, Value> {
+    fn operands(params: &Params) -> Option<(i64, i64)> {
+        let (minuend, subtrahend) = match params {
+            Params::Named(_) => (params.get_named("minuend"), params.get_named("subtrahend")),
+            _ => (params.get_positional(0), params.get_positional(1)),
+        };
+        Some((minuend?.as_i64()?, subtrahend?.as_i64()?))
+    }
+
+    let mut router = Router::new();
+    router
+        .register("sum", |p| match p {
+            Params::Positional(values) => {
+                Ok(values.iter().filter_map(Value::as_i64).sum::<i64>().into())
+            }
+            _ => Err(ResponseError::invalid_params()),
+        })
+        .register("subtract", |p| {
+            let (minuend, subtrahend) = operands(p).ok_or_else(ResponseError::invalid_params)?;
+            Ok((minuend - subtrahend).into())
+        })
+        .register("update", |_| Ok(Value::Null))
+        .register("notify_hello", |_| Ok(Value::Null))
+        .register("get_data", |_| Ok(json(r#"["hello", 5]"#)));
+    router
+}
+
+/// Reply to a single call, checking that it reserializes to itself.
+fn reply_to(router: &Router<Params, Value>, call: Value) -> Option<SpecResponse> {
+    match serde_json::from_value::<SpecMessage>(call.clone()) {
+        Ok(message) => {
+            assert_eq!(serde_json::to_value(&message).unwrap(), call);
+            router.handle(&message)
+        }
+        Err(_) => Some(ResponseError::invalid_request().into()),
+    }
+}
+
+/// Reply to the text sent by a Client, as a Server would.
+fn reply(text: &str) -> Option<Value> {
+    let router = router();
+
+    let reply = match serde_json::from_str::<Value>(text) {
+        Ok(calls @ Value::Array(_)) => match serde_json::from_value::<Batch<Value>>(calls) {
+            Ok(batch) => {
+                let responses = batch.process(|call| reply_to(&router, call))?;
+                serde_json::to_value(responses)
+            }
+            Err(_) => serde_json::to_value(SpecResponse::from(ResponseError::invalid_request())),
+        },
+        Ok(call) => serde_json::to_value(reply_to(&router, call)?),
+        Err(_) => serde_json::to_value(SpecResponse::from(ResponseError::parse_error())),
+    };
+
+    Some(reply.unwrap())
+}
+
+/// Assert that the Server replies to `call` with `expected`.
+fn assert_reply(call: &str, expected: Option<&str>) {
+    assert_eq!(reply(call), expected.map(json));
+}
+
+#[test]
+fn test_spec_positional_parameters() {
+    assert_reply(
+        r#"{"jsonrpc": "2.0", "method": "subtract", "params": [42, 23], "id": 1}"#,
+        Some(r#"{"jsonrpc": "2.0", "result": 19, "id": 1}"#),
+    );
+    assert_reply(
+        r#"{"jsonrpc": "2.0", "method": "subtract", "params": [23, 42], "id": 2}"#,
+        Some(r#"{"jsonrpc": "2.0", "result": -19, "id": 2}"#),
+    );
+}
+
+#[test]
+fn test_spec_named_parameters() {
+    assert_reply(
+        r#"{"jsonrpc": "2.0", "method": "subtract", "params": {"subtrahend": 23, "minuend": 42}, "id": 3}"#,
+        Some(r#"{"jsonrpc": "2.0", "result": 19, "id": 3}"#),
+    );
+    assert_reply(
+        r#"{"jsonrpc": "2.0", "method": "subtract", "params": {"minuend": 42, "subtrahend": 23}, "id": 4}"#,
+        Some(r#"{"jsonrpc": "2.0", "result": 19, "id": 4}"#),
+    );
+}
+
+#[test]
+fn test_spec_notification() {
+    assert_reply(
+        r#"{"jsonrpc": "2.0", "method": "update", "params": [1,2,3,4,5]}"#,
+        None,
+    );
+    assert_reply(r#"{"jsonrpc": "2.0", "method": "foobar"}"#, None);
+}
+
+#[test]
+fn test_spec_non_existent_method() {
+    assert_reply(
+        r#"{"jsonrpc": "2.0", "method": "foobar", "id": "1"}"#,
+        Some(
+            r#"{"jsonrpc": "2.0", "error": {"code": -32601, "message": "Method not found"}, "id": "1"}"#,
+        ),
+    );
+}
+
+#[test]
+fn test_spec_invalid_json() {
+    assert_reply(
+        r#"{"jsonrpc": "2.0", "method": "foobar, "params": "bar", "baz]"#,
+        Some(
+            r#"{"jsonrpc": "2.0", "error": {"code": -32700, "message": "Parse error"}, "id": null}"#,
+        ),
+    );
+}
+
+#[test]
+fn test_spec_invalid_request() {
+    assert!(matches!(
+        Request::<Params>::try_from(json(r#"{"jsonrpc": "2.0", "method": 1, "params": "bar"}"#)),
+        Err(Error::Json(_))
+    ));
+    assert_reply(
+        r#"{"jsonrpc": "2.0", "method": 1, "params": "bar"}"#,
+        Some(
+            r#"{"jsonrpc": "2.0", "error": {"code": -32600, "message": "Invalid Request"}, "id": null}"#,
+        ),
+    );
+}
+
+#[test]
+fn test_spec_batch_invalid_json() {
+    assert_reply(
+        r#"[
+          {"jsonrpc": "2.0", "method": "sum", "params": [1,2,4], "id": "1"},
+          {"jsonrpc": "2.0", "method"
+        ]"#,
+        Some(
+            r#"{"jsonrpc": "2.0", "error": {"code": -32700, "message": "Parse error"}, "id": null}"#,
+        ),
+    );
+}
+
+#[test]
+fn test_spec_empty_batch() {
+    assert_reply(
+        "[]",
+        Some(
+            r#"{"jsonrpc": "2.0", "error": {"code": -32600, "message": "Invalid Request"}, "id": null}"#,
+        ),
+    );
+}
+
+#[test]
+fn test_spec_invalid_non_empty_batch() {
+    assert_reply(
+        "[1]",
+        Some(
+            r#"[
+              {"jsonrpc": "2.0", "error": {"code": -32600, "message": "Invalid Request"}, "id": null}
+            ]"#,
+        ),
+    );
+}
+
+#[test]
+fn test_spec_invalid_batch() {
+    assert_reply(
+        "[1,2,3]",
+        Some(
+            r#"[
+              {"jsonrpc": "2.0", "error": {"code": -32600, "message": "Invalid Request"}, "id": null},
+              {"jsonrpc": "2.0", "error": {"code": -32600, "message": "Invalid Request"}, "id": null},
+              {"jsonrpc": "2.0", "error": {"code": -32600, "message": "Invalid Request"}, "id": null}
+            ]"#,
+        ),
+    );
+}
+
+#[test]
+fn test_spec_batch() {
+    assert_reply(
+        r#"[
+          {"jsonrpc": "2.0", "method": "sum", "params": [1,2,4], "id": "1"},
+          {"jsonrpc": "2.0", "method": "notify_hello", "params": [7]},
+          {"jsonrpc": "2.0", "method": "subtract", "params": [42,23], "id": "2"},
+          {"foo": "boo"},
+          {"jsonrpc": "2.0", "method": "foo.get", "params": {"name": "myself"}, "id": "5"},
+          {"jsonrpc": "2.0", "method": "get_data", "id": "9"}
+        ]"#,
+        Some(
+            r#"[
+              {"jsonrpc": "2.0", "result": 7, "id": "1"},
+              {"jsonrpc": "2.0", "result": 19, "id": "2"},
+              {"jsonrpc": "2.0", "error": {"code": -32600, "message": "Invalid Request"}, "id": null},
+              {"jsonrpc": "2.0", "error": {"code": -32601, "message": "Method not found"}, "id": "5"},
+              {"jsonrpc": "2.0", "result": ["hello", 5], "id": "9"}
+            ]"#,
+        ),
+    );
+}
+
+#[test]
+fn test_spec_batch_all_notifications() {
+    assert_reply(
+        r#"[
+          {"jsonrpc": "2.0", "method": "notify_sum", "params": [1,2,4]},
+          {"jsonrpc": "2.0", "method": "notify_hello", "params": [7]}
+        ]"#,
+        None,
+    );
+}
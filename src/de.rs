@@ -0,0 +1,147 @@
+//! Deserialization helpers.
+
+use alloc::{borrow::Cow, string::ToString};
+use core::marker::PhantomData;
+
+use serde::{
+    de::{self, DeserializeOwned},
+    forward_to_deserialize_any, Deserialize,
+};
+use serde_json::Value;
+
+use crate::{Error, Version};
+
+/// A String borrowed from the input when possible.
+#[derive(Deserialize)]
+#[serde(transparent)]
+pub(crate) struct CowStr<'a>(#[serde(borrow)] pub(crate) Cow<'a, str>);
+
+/// Deserialize an object from a JSON string, rejecting any top-level member
+/// not in `members`.
+pub(crate) fn from_str_strict<T: DeserializeOwned>(s: &str, members: &[&str]) -> Result<T, Error> {
+    let value: Value = serde_json::from_str(s)?;
+    if let Some(object) = value.as_object() {
+        if let Some(member) = object.keys().find(|k| !members.contains(&k.as_str())) {
+            return Err(Error::UnknownMember {
+                member: member.to_string(),
+            });
+        }
+    }
+
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Deserialize a member which MAY be omitted, but not set to `null`.
+pub(crate) fn some<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// The [`Version`] of an object missing the **jsonrpc** member.
+///
+/// The member is required, unless the `v1` feature is enabled.
+pub(crate) fn missing_version<E: de::Error>() -> Result<Version, E> {
+    #[cfg(feature = "v1")]
+    return Ok(Version::V1);
+    #[cfg(not(feature = "v1"))]
+    Err(E::missing_field("jsonrpc"))
+}
+
+/// A **params** member which MAY be omitted.
+///
+/// When absent, the params are deserialized as if they were `null`, so that
+/// `()`, `Option`, [`Value`], [`Params`](crate::Params) and
+/// [`NoParams`](crate::NoParams) params accept a missing member. Any other
+/// type (e.g. a `Vec` or a struct) fails with a missing field error.
+pub(crate) struct Absent<P>(Option<P>);
+
+impl<P> Default for Absent<P> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<'de, P: Deserialize<'de>> Deserialize<'de> for Absent<P> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        P::deserialize(deserializer).map(|params| Self(Some(params)))
+    }
+}
+
+impl<P> Absent<P> {
+    pub(crate) fn into_present<'de, E: de::Error>(self) -> Result<P, E>
+    where
+        P: Deserialize<'de>,
+    {
+        match self.0 {
+            Some(params) => Ok(params),
+            None => P::deserialize(MissingParams(PhantomData)),
+        }
+    }
+}
+
+/// Fail with a missing **params** error, rather than an unhelpful invalid
+/// type error, for types requiring a value.
+macro_rules! missing_params {
+    ($($method:ident($($ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(self, $(_: $ty,)* _visitor: V) -> Result<V::Value, E> {
+                Err(E::missing_field("params"))
+            }
+        )*
+    };
+}
+/// A [`Deserializer`](de::Deserializer) for an absent **params** member.
+struct MissingParams<E>(PhantomData<E>);
+
+impl<'de, E: de::Error> de::Deserializer<'de> for MissingParams<E> {
+    type Error = E;
+
+    /// Self-describing types, e.g. [`Value`], see an absent member as Null.
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        visitor.visit_none()
+    }
+
+    fn deserialize_unit<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf identifier ignored_any
+    }
+
+    missing_params! {
+        deserialize_seq(),
+        deserialize_tuple(usize),
+        deserialize_tuple_struct(&'static str, usize),
+        deserialize_map(),
+        deserialize_struct(&'static str, &'static [&'static str]),
+        deserialize_enum(&'static str, &'static [&'static str]),
+    }
+}
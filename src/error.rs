@@ -0,0 +1,280 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::fmt;
+
+use crate::{JSONRPC_VERSION, SERVER_ERROR_RANGE};
+
+/// Errors of this crate.
+///
+/// The deserializers report the specific variants through their error
+/// messages, which are recognized again when converting from a
+/// [`serde_json::Error`].
+///
+/// This recovery depends on the format of serde_json's messages: the message
+/// of a custom error raised while parsing input gets a
+/// `" at line {line} column {column}"` suffix, as of serde_json 1.0.91, the
+/// minimum version this crate requires. A message in any other format is kept
+/// as an [`Error::Json`].
+#[derive(Debug)]
+pub enum Error {
+    /// The **jsonrpc** member isn't exactly "2.0".
+    InvalidVersion { found: String },
+    /// A Response contains both a **result** and an **error** member.
+    BothResultAndError,
+    /// A Response contains neither a **result** nor an **error** member.
+    NeitherResultNorError,
+    /// A batch is an empty Array.
+    EmptyBatch,
+    /// An object contains a member not defined by the specification, see the
+    /// `from_json_str_strict` functions.
+    UnknownMember { member: String },
+    /// The **message** of an **error** is empty, see [`Response::validate`](crate::Response::validate).
+    EmptyErrorMessage,
+    /// The **code** of an **error** is reserved for future use, see
+    /// [`Response::validate`](crate::Response::validate).
+    ReservedErrorCode { code: i64 },
+    /// The **code** of a server error isn't within
+    /// [`SERVER_ERROR_RANGE`](crate::SERVER_ERROR_RANGE), see
+    /// [`ResponseError::server_error_checked`](crate::ResponseError::server_error_checked).
+    InvalidServerErrorCode { code: i64 },
+    /// The **method** name is reserved, see [`is_reserved_method`](crate::is_reserved_method).
+    ReservedMethod { method: String },
+    /// The **method** name is empty, see [`MethodName`](crate::MethodName).
+    EmptyMethod,
+    /// A frame header is invalid, or lacks a `Content-Length`.
+    ///
+    /// Only returned by `JsonRpcCodec`, but defined without the `codec`
+    /// feature too, so that enabling it doesn't break exhaustive matches.
+    InvalidHeader { header: String },
+    /// A frame is longer than the `JsonRpcCodec::max_frame_len`.
+    ///
+    /// Only returned by `JsonRpcCodec`, like [`Error::InvalidHeader`].
+    FrameTooLarge { len: usize, max: usize },
+    /// The **code** of an **error** doesn't fit in the `i32` of a jsonrpsee
+    /// `ErrorObject`.
+    ///
+    /// Only returned with the `jsonrpsee` feature, like
+    /// [`Error::InvalidHeader`].
+    ErrorCodeOutOfRange { code: i64 },
+    /// The JSON is invalid, or doesn't match the expected object.
+    Json(serde_json::Error),
+}
+
+const INVALID_VERSION: &str = "invalid jsonrpc version: expected ";
+const BOTH_RESULT_AND_ERROR: &str =
+    "response must contain exactly one of result or error, found both";
+const NEITHER_RESULT_NOR_ERROR: &str =
+    "response must contain exactly one of result or error, found neither";
+const EMPTY_BATCH: &str = "batch must contain at least one value";
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidVersion { found } => {
+                write!(f, "{INVALID_VERSION}{JSONRPC_VERSION:?}, found {found}")
+            }
+            Error::BothResultAndError => f.write_str(BOTH_RESULT_AND_ERROR),
+            Error::NeitherResultNorError => f.write_str(NEITHER_RESULT_NOR_ERROR),
+            Error::EmptyBatch => f.write_str(EMPTY_BATCH),
+            Error::UnknownMember { member } => write!(f, "unknown member {member:?}"),
+            Error::EmptyErrorMessage => f.write_str("error message is empty"),
+            Error::ReservedErrorCode { code } => {
+                write!(f, "error code {code} is reserved for future use")
+            }
+            Error::InvalidServerErrorCode { code } => {
+                write!(
+                    f,
+                    "error code {code} is not within the server error range {}..={}",
+                    SERVER_ERROR_RANGE.start(),
+                    SERVER_ERROR_RANGE.end()
+                )
+            }
+            Error::ReservedMethod { method } => {
+                write!(
+                    f,
+                    "method name {method:?} is reserved for rpc-internal methods"
+                )
+            }
+            Error::EmptyMethod => f.write_str("method name is empty"),
+            Error::InvalidHeader { header } => write!(f, "invalid header {header:?}"),
+            Error::FrameTooLarge { len, max } => {
+                write!(
+                    f,
+                    "frame of {len} bytes is longer than the maximum of {max}"
+                )
+            }
+            Error::ErrorCodeOutOfRange { code } => {
+                write!(f, "error code {code} is out of the i32 range")
+            }
+            Error::Json(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// IO errors are reported the way serde_json reports them.
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Json(serde_json::Error::io(e))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        let message = e.to_string();
+        let message = match e.line() {
+            0 => &message,
+            line => message
+                .strip_suffix(&format!(" at line {line} column {}", e.column()))
+                .unwrap_or(&message),
+        };
+
+        let found = message
+            .strip_prefix(INVALID_VERSION)
+            .and_then(|message| message.strip_prefix(&format!("{JSONRPC_VERSION:?}, found ")));
+
+        if let Some(found) = found {
+            Error::InvalidVersion {
+                found: found.to_string(),
+            }
+        } else {
+            match message {
+                BOTH_RESULT_AND_ERROR => Error::BothResultAndError,
+                NEITHER_RESULT_NOR_ERROR => Error::NeitherResultNorError,
+                EMPTY_BATCH => Error::EmptyBatch,
+                _ => Error::Json(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Batch, Message, Request, Response};
+
+    fn from_str<T: serde::de::DeserializeOwned>(s: &str) -> Result<T, Error> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    #[test]
+    fn test_error_variants() {
+        assert!(matches!(
+            from_str::<Request<()>>(r#"{"jsonrpc":"1.0","method":"m","params":null}"#),
+            Err(Error::InvalidVersion { found }) if found == r#""1.0""#
+        ));
+        assert!(matches!(
+            from_str::<Response<i32, ()>>(
+                r#"{"jsonrpc":"2.0","result":1,"error":{"code":1,"message":"m"},"id":1}"#
+            ),
+            Err(Error::BothResultAndError)
+        ));
+        assert!(matches!(
+            from_str::<Response<i32, ()>>(r#"{"jsonrpc":"2.0","id":1}"#),
+            Err(Error::NeitherResultNorError)
+        ));
+        assert!(matches!(
+            from_str::<Batch<Request<()>>>("[]"),
+            Err(Error::EmptyBatch)
+        ));
+        assert!(matches!(
+            from_str::<Request<()>>(r#"{"jsonrpc":"2.0""#),
+            Err(Error::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_error_variants_nested() {
+        let invalid_version = r#"{"jsonrpc":"1.0","method":"m","params":null,"id":1}"#;
+        let both = r#"{"jsonrpc":"2.0","result":1,"error":{"code":1,"message":"m"},"id":1}"#;
+        let neither = r#"{"jsonrpc":"2.0","id":1}"#;
+
+        assert!(matches!(
+            from_str::<Batch<Request<()>>>(&format!("[{invalid_version}]")),
+            Err(Error::InvalidVersion { found }) if found == r#""1.0""#
+        ));
+        assert!(matches!(
+            from_str::<Message<(), i32, ()>>(invalid_version),
+            Err(Error::InvalidVersion { found }) if found == r#""1.0""#
+        ));
+        assert!(matches!(
+            from_str::<Batch<Response<i32, ()>>>(&format!("[{neither}, {both}]")),
+            Err(Error::NeitherResultNorError)
+        ));
+        assert!(matches!(
+            from_str::<Batch<Message<(), i32, ()>>>(&format!("[\n{both}\n]")),
+            Err(Error::BothResultAndError)
+        ));
+        assert!(matches!(
+            from_str::<Vec<Batch<Request<()>>>>("[[]]"),
+            Err(Error::EmptyBatch)
+        ));
+    }
+
+    #[test]
+    fn test_error_message_format() {
+        use serde::de::Error as _;
+
+        // the suffix stripped by `From<serde_json::Error>`
+        let input = "{\n\"jsonrpc\":\"2.0\",\n\"id\":1}";
+        let e = serde_json::from_str::<Response<i32, ()>>(input).unwrap_err();
+        assert_eq!((e.line(), e.column()), (3, 7));
+        assert_eq!(
+            e.to_string(),
+            format!("{NEITHER_RESULT_NOR_ERROR} at line 3 column 7")
+        );
+        assert!(matches!(Error::from(e), Error::NeitherResultNorError));
+
+        // no position, so no suffix, when not parsing input
+        let e = serde_json::Error::custom(EMPTY_BATCH);
+        assert_eq!(e.line(), 0);
+        assert_eq!(e.to_string(), EMPTY_BATCH);
+        assert!(matches!(Error::from(e), Error::EmptyBatch));
+    }
+
+    #[test]
+    fn test_error_display() {
+        let error = Error::InvalidVersion {
+            found: r#""1.0""#.to_string(),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            format!(r#"invalid jsonrpc version: expected "{JSONRPC_VERSION}", found "1.0""#)
+        );
+
+        // defined whether or not the `codec` and `jsonrpsee` features are
+        // enabled
+        assert_eq!(
+            Error::FrameTooLarge { len: 2, max: 1 }.to_string(),
+            "frame of 2 bytes is longer than the maximum of 1"
+        );
+        assert_eq!(
+            Error::ErrorCodeOutOfRange { code: 1 << 40 }.to_string(),
+            "error code 1099511627776 is out of the i32 range"
+        );
+    }
+
+    #[test]
+    fn test_error_from_value() {
+        let value = serde_json::json!({"jsonrpc": 2, "method": "m", "params": null});
+
+        assert!(matches!(
+            Request::<()>::try_from(value),
+            Err(Error::InvalidVersion { found }) if found == "2"
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+use core::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+/// Invalid JSON was received by the server.
+pub const PARSE_ERROR: i64 = -32700;
+/// The JSON sent is not a valid Request object.
+pub const INVALID_REQUEST: i64 = -32600;
+/// The method does not exist / is not available.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// Invalid method parameter(s).
+pub const INVALID_PARAMS: i64 = -32602;
+/// Internal JSON-RPC error.
+pub const INTERNAL_ERROR: i64 = -32603;
+/// Reserved for implementation-defined server-errors.
+pub const SERVER_ERROR_RANGE: RangeInclusive<i64> = -32099..=-32000;
+
+/// The **code** of a [`ResponseError`](crate::ResponseError).
+///
+/// | code | message | meaning |
+/// |------|---------|---------|
+/// | -32700 | Parse error | Invalid JSON was received by the server.<br />An error occurred on the server while parsing the JSON text. |
+/// | -32600 | Invalid Request | The JSON sent is not a valid Request object. |
+/// | -32601 | Method not found | The method does not exist / is not available. |
+/// | -32602 | Invalid params | Invalid method parameter(s). |
+/// | -32603 | Internal error | Internal JSON-RPC error. |
+/// | -32000 to -32099 | Server error | Reserved for implementation-defined server-errors. |
+///
+/// The remainder of the space is available for application defined errors.
+///
+/// Serialized as the bare integer code.
+///
+/// New pre-defined codes may be added, so matches need a wildcard arm.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+#[serde(from = "i64", into = "i64")]
+#[non_exhaustive]
+pub enum ErrorCode {
+    ParseError,
+    InvalidRequest,
+    MethodNotFound,
+    InvalidParams,
+    InternalError,
+    /// A code within -32000 to -32099.
+    ServerError(i64),
+    /// Any other code.
+    Application(i64),
+}
+
+/// The kind of an error code, see [`ErrorCode::classify`].
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum ErrorKind {
+    /// One of the codes defined by the specification.
+    Predefined,
+    /// A code within [`SERVER_ERROR_RANGE`].
+    ServerReserved,
+    /// Any other code.
+    Application,
+}
+
+impl ErrorCode {
+    /// Classify an integer error code.
+    pub fn classify(code: i64) -> ErrorKind {
+        match ErrorCode::from(code) {
+            ErrorCode::ServerError(_) => ErrorKind::ServerReserved,
+            ErrorCode::Application(_) => ErrorKind::Application,
+            _ => ErrorKind::Predefined,
+        }
+    }
+
+    /// The integer value of the code.
+    pub fn code(&self) -> i64 {
+        match self {
+            ErrorCode::ParseError => PARSE_ERROR,
+            ErrorCode::InvalidRequest => INVALID_REQUEST,
+            ErrorCode::MethodNotFound => METHOD_NOT_FOUND,
+            ErrorCode::InvalidParams => INVALID_PARAMS,
+            ErrorCode::InternalError => INTERNAL_ERROR,
+            ErrorCode::ServerError(code) | ErrorCode::Application(code) => *code,
+        }
+    }
+
+    /// The canonical message of the code.
+    pub fn message(&self) -> &'static str {
+        match self {
+            ErrorCode::ParseError => "Parse error",
+            ErrorCode::InvalidRequest => "Invalid Request",
+            ErrorCode::MethodNotFound => "Method not found",
+            ErrorCode::InvalidParams => "Invalid params",
+            ErrorCode::InternalError => "Internal error",
+            ErrorCode::ServerError(_) => "Server error",
+            ErrorCode::Application(_) => "Application error",
+        }
+    }
+}
+
+impl From<i64> for ErrorCode {
+    fn from(code: i64) -> Self {
+        match code {
+            PARSE_ERROR => ErrorCode::ParseError,
+            INVALID_REQUEST => ErrorCode::InvalidRequest,
+            METHOD_NOT_FOUND => ErrorCode::MethodNotFound,
+            INVALID_PARAMS => ErrorCode::InvalidParams,
+            INTERNAL_ERROR => ErrorCode::InternalError,
+            _ if SERVER_ERROR_RANGE.contains(&code) => ErrorCode::ServerError(code),
+            _ => ErrorCode::Application(code),
+        }
+    }
+}
+
+impl From<ErrorCode> for i64 {
+    fn from(code: ErrorCode) -> Self {
+        code.code()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code_from() {
+        for (code, error_code) in [
+            (-32700, ErrorCode::ParseError),
+            (-32600, ErrorCode::InvalidRequest),
+            (-32601, ErrorCode::MethodNotFound),
+            (-32602, ErrorCode::InvalidParams),
+            (-32603, ErrorCode::InternalError),
+            (-32000, ErrorCode::ServerError(-32000)),
+            (-32099, ErrorCode::ServerError(-32099)),
+            (-32100, ErrorCode::Application(-32100)),
+            (1, ErrorCode::Application(1)),
+        ] {
+            assert_eq!(ErrorCode::from(code), error_code);
+            assert_eq!(i64::from(error_code), code);
+        }
+    }
+
+    #[test]
+    fn test_error_code_classify() {
+        for (code, kind) in [
+            (PARSE_ERROR, ErrorKind::Predefined),
+            (INTERNAL_ERROR, ErrorKind::Predefined),
+            (-31999, ErrorKind::Application),
+            (-32000, ErrorKind::ServerReserved),
+            (-32099, ErrorKind::ServerReserved),
+            (-32100, ErrorKind::Application),
+            (0, ErrorKind::Application),
+        ] {
+            assert_eq!(ErrorCode::classify(code), kind, "{code}");
+        }
+    }
+
+    #[test]
+    fn test_error_code_serde() {
+        assert_eq!(
+            serde_json::to_string(&ErrorCode::MethodNotFound).unwrap(),
+            "-32601"
+        );
+        assert_eq!(
+            serde_json::from_str::<ErrorCode>("-32001").unwrap(),
+            ErrorCode::ServerError(-32001)
+        );
+        assert!(serde_json::from_str::<ErrorCode>("\"-32601\"").is_err());
+    }
+
+    #[test]
+    fn test_error_code_message() {
+        assert_eq!(ErrorCode::ParseError.message(), "Parse error");
+        assert_eq!(ErrorCode::InvalidRequest.message(), "Invalid Request");
+        assert_eq!(ErrorCode::MethodNotFound.message(), "Method not found");
+        assert_eq!(ErrorCode::InvalidParams.message(), "Invalid params");
+        assert_eq!(ErrorCode::InternalError.message(), "Internal error");
+        assert_eq!(ErrorCode::ServerError(-32000).message(), "Server error");
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(feature = "uuid")]
+use alloc::string::ToString;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::Id;
+
+/// Generates unique, increasing Number ids for the Requests of a Client.
+///
+/// Can be shared between threads, each call to [`IdGenerator::next`] returns
+/// a different id.
+///
+/// Ids are within `0..=i64::MAX`, so that they are always [`Id::Number`]s.
+/// After [`i64::MAX`] the generator wraps around to 0, rather than
+/// saturating, which would return the same id over and over.
+///
+/// Only available on targets with 64-bit atomics.
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug, Default)]
+pub struct IdGenerator {
+    next: AtomicU64,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl IdGenerator {
+    /// Start generating ids from 0.
+    pub fn new() -> Self {
+        Self::starting_at(0)
+    }
+
+    /// `first` is wrapped into `0..=i64::MAX`.
+    pub fn starting_at(first: u64) -> Self {
+        Self {
+            next: AtomicU64::new(first),
+        }
+    }
+
+    pub fn next(&self) -> Id {
+        let next = self.next.fetch_add(1, Ordering::Relaxed) & i64::MAX as u64;
+        Id::Number(next as i64)
+    }
+}
+
+/// Generates random (version 4) UUID String ids, e.g.
+/// `"67e55044-10b1-426f-9247-bb680e5fe0c8"`.
+///
+/// Unlike an [`IdGenerator`](crate::IdGenerator), several Clients can
+/// generate ids without coordinating, at the cost of a String id.
+#[cfg(feature = "uuid")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UuidGenerator;
+
+#[cfg(feature = "uuid")]
+impl UuidGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn next(&self) -> Id {
+        Id::Str(uuid::Uuid::new_v4().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{collections::HashSet, thread};
+
+    #[cfg(target_has_atomic = "64")]
+    #[test]
+    fn test_id_generator_monotonic() {
+        let generator = IdGenerator::starting_at(5);
+
+        assert_eq!(
+            [generator.next(), generator.next(), generator.next()],
+            [Id::from(5), Id::from(6), Id::from(7)]
+        );
+    }
+
+    #[cfg(target_has_atomic = "64")]
+    #[test]
+    fn test_id_generator_wraps_around() {
+        let generator = IdGenerator::starting_at(i64::MAX as u64 - 1);
+
+        assert_eq!(
+            [generator.next(), generator.next(), generator.next()],
+            [
+                Id::Number(i64::MAX - 1),
+                Id::Number(i64::MAX),
+                Id::Number(0)
+            ]
+        );
+
+        let generator = IdGenerator::starting_at(u64::MAX);
+        assert_eq!(
+            [generator.next(), generator.next()],
+            [Id::Number(i64::MAX), Id::Number(0)]
+        );
+    }
+
+    #[cfg(target_has_atomic = "64")]
+    #[test]
+    fn test_id_generator_concurrent() {
+        let generator = IdGenerator::new();
+
+        let ids = thread::scope(|scope| {
+            let threads = [(); 4]
+                .map(|_| scope.spawn(|| (0..1000).map(|_| generator.next()).collect::<Vec<_>>()));
+            threads.map(|thread| thread.join().unwrap())
+        });
+
+        for ids in &ids {
+            assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        }
+        assert_eq!(ids.iter().flatten().collect::<HashSet<_>>().len(), 4000);
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_uuid_generator_concurrent() {
+        let ids = thread::scope(|scope| {
+            let threads = [(); 4].map(|_| {
+                scope.spawn(|| {
+                    let generator = UuidGenerator::new();
+                    (0..1000).map(|_| generator.next()).collect::<Vec<_>>()
+                })
+            });
+            threads.map(|thread| thread.join().unwrap())
+        });
+
+        for id in ids.iter().flatten() {
+            let id = id.as_str().unwrap();
+            assert_eq!(uuid::Uuid::parse_str(id).unwrap().get_version_num(), 4);
+            assert_eq!(id.len(), 36);
+        }
+        assert_eq!(ids.iter().flatten().collect::<HashSet<_>>().len(), 4000);
+    }
+}
@@ -0,0 +1,656 @@
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use serde::{de, Deserialize, Serialize};
+use serde_json::Value;
+
+/// An identifier established by the Client.
+///
+/// The id MUST contain a String, Number, or NULL value if included.
+/// The value SHOULD normally not be Null and Numbers SHOULD NOT contain
+/// fractional parts.
+///
+/// The Server MUST reply with the same value in the Response object if
+/// included. This member is used to correlate the context between the two
+/// objects.
+///
+/// The Null id of a Response is represented by the absence of an [`Id`]
+/// (`Option::None`). A Request's Null id is kept as `Id::Other(Value::Null)`,
+/// since a Request without an id is a Notification.
+///
+/// Some non-conformant Servers echo back ids of any type, these are kept
+/// verbatim in [`Id::Other`]. Numbers with a zero fractional part (e.g.
+/// `1.0`) are deserialized as [`Id::Number`], but fractional Numbers (e.g.
+/// `1.5`) fail to deserialize.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Id {
+    Number(i64),
+    Str(String),
+    /// Any other value, e.g. a Boolean, an Array or a Number larger than
+    /// [`i64::MAX`].
+    Other(Value),
+}
+
+impl Id {
+    /// The id, if it is a [`Id::Number`].
+    pub fn as_number(&self) -> Option<i64> {
+        match self {
+            Id::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// The id, if it is a [`Id::Str`].
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Id::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_id_ref(&self) -> IdRef<'_> {
+        match self {
+            Id::Number(n) => IdRef::Number(*n),
+            Id::Str(s) => IdRef::Str(s),
+            Id::Other(v) => IdRef::Other(v),
+        }
+    }
+}
+
+/// Formats the id as in JSON, e.g. `1` or `"abc"`.
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Id::Number(n) => n.fmt(f),
+            Id::Str(s) => write!(f, "{s:?}"),
+            Id::Other(v) => v.fmt(f),
+        }
+    }
+}
+
+/// Hashes the same as [`Id::as_id_ref`].
+impl Hash for Id {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_id_ref().hash(state);
+    }
+}
+
+/// Ordered the same as [`Id::as_id_ref`].
+impl Ord for Id {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_id_ref().cmp(&other.as_id_ref())
+    }
+}
+
+impl PartialOrd for Id {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Defaults to `Id::Number(0)`.
+impl Default for Id {
+    fn default() -> Self {
+        Self::Number(0)
+    }
+}
+
+impl From<i64> for Id {
+    fn from(n: i64) -> Self {
+        Self::Number(n)
+    }
+}
+
+impl From<i32> for Id {
+    fn from(n: i32) -> Self {
+        Self::Number(n.into())
+    }
+}
+
+impl From<u32> for Id {
+    fn from(n: u32) -> Self {
+        Self::Number(n.into())
+    }
+}
+
+/// Numbers larger than [`i64::MAX`] are kept as an [`Id::Other`] Number, so
+/// that they still serialize as a Number, the same as when deserialized.
+impl From<u64> for Id {
+    fn from(n: u64) -> Self {
+        match i64::try_from(n) {
+            Ok(n) => Self::Number(n),
+            Err(_) => Self::Other(Value::from(n)),
+        }
+    }
+}
+
+impl From<String> for Id {
+    fn from(s: String) -> Self {
+        Self::Str(s)
+    }
+}
+
+impl From<&str> for Id {
+    fn from(s: &str) -> Self {
+        Self::Str(s.to_string())
+    }
+}
+
+/// Strings and Numbers fitting in an [`i64`] become [`Id::Str`] and
+/// [`Id::Number`], anything else becomes [`Id::Other`].
+impl From<Value> for Id {
+    fn from(value: Value) -> Self {
+        if let Some(n) = value.as_i64() {
+            return Self::Number(n);
+        }
+
+        match value {
+            Value::String(s) => Self::Str(s),
+            value => Self::Other(value),
+        }
+    }
+}
+
+/// A borrowed [`Id`].
+///
+/// An [`IdRef`] is equal to, hashes the same as and is ordered the same as
+/// the [`Id`] it borrows. This allows looking up [`Id`] keys without
+/// allocating, through [`AsIdRef`]:
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use jsonrpc_types::{AsIdRef, Id, IdRef};
+///
+/// let map = HashMap::from([(Id::from("abc"), 1), (Id::from(2), 2)]);
+///
+/// assert_eq!(map.get(&IdRef::Str("abc") as &dyn AsIdRef), Some(&1));
+/// assert_eq!(map.get(&IdRef::Number(2) as &dyn AsIdRef), Some(&2));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum IdRef<'a> {
+    Number(i64),
+    Str(&'a str),
+    Other(&'a Value),
+}
+
+impl IdRef<'_> {
+    fn discriminant(&self) -> u8 {
+        match self {
+            IdRef::Number(_) => 0,
+            IdRef::Str(_) => 1,
+            IdRef::Other(_) => 2,
+        }
+    }
+}
+
+impl Hash for IdRef<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.discriminant().hash(state);
+        match self {
+            IdRef::Number(n) => n.hash(state),
+            IdRef::Str(s) => s.hash(state),
+            IdRef::Other(v) => v.to_string().hash(state),
+        }
+    }
+}
+
+/// Ids are totally ordered: all Numbers come before all Strings, which come
+/// before all other values. Numbers are ordered by value, Strings
+/// lexicographically and other values by their JSON text.
+impl Ord for IdRef<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (IdRef::Number(a), IdRef::Number(b)) => a.cmp(b),
+            (IdRef::Str(a), IdRef::Str(b)) => a.cmp(b),
+            (IdRef::Other(a), IdRef::Other(b)) => a.to_string().cmp(&b.to_string()),
+            (a, b) => a.discriminant().cmp(&b.discriminant()),
+        }
+    }
+}
+
+impl PartialOrd for IdRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> From<&'a Id> for IdRef<'a> {
+    fn from(id: &'a Id) -> Self {
+        id.as_id_ref()
+    }
+}
+
+impl From<IdRef<'_>> for Id {
+    fn from(id: IdRef<'_>) -> Self {
+        match id {
+            IdRef::Number(n) => Id::Number(n),
+            IdRef::Str(s) => Id::Str(s.to_string()),
+            IdRef::Other(v) => Id::Other(v.clone()),
+        }
+    }
+}
+
+/// A type viewable as an [`IdRef`], used as the lookup key of maps keyed by
+/// [`Id`] (`&dyn AsIdRef`), see [`IdRef`].
+pub trait AsIdRef {
+    fn as_id_ref(&self) -> IdRef<'_>;
+}
+
+impl AsIdRef for Id {
+    fn as_id_ref(&self) -> IdRef<'_> {
+        Id::as_id_ref(self)
+    }
+}
+
+impl AsIdRef for IdRef<'_> {
+    fn as_id_ref(&self) -> IdRef<'_> {
+        *self
+    }
+}
+
+impl PartialEq for dyn AsIdRef + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_id_ref() == other.as_id_ref()
+    }
+}
+
+impl Eq for dyn AsIdRef + '_ {}
+
+impl Hash for dyn AsIdRef + '_ {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_id_ref().hash(state);
+    }
+}
+
+impl Ord for dyn AsIdRef + '_ {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_id_ref().cmp(&other.as_id_ref())
+    }
+}
+
+impl PartialOrd for dyn AsIdRef + '_ {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Borrow<dyn AsIdRef + 'a> for Id {
+    fn borrow(&self) -> &(dyn AsIdRef + 'a) {
+        self
+    }
+}
+
+impl Serialize for Id {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if !serializer.is_human_readable() {
+            return BinaryId::from(self).serialize(serializer);
+        }
+
+        match self {
+            Id::Number(n) => serializer.serialize_i64(*n),
+            Id::Str(s) => serializer.serialize_str(s),
+            Id::Other(v) => v.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Id {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return match BinaryId::deserialize(deserializer)? {
+                BinaryId::Number(n) => Ok(Id::Number(n)),
+                BinaryId::Str(s) => Ok(Id::Str(s.into_owned())),
+                BinaryId::Other(json) => serde_json::from_str(&json)
+                    .map(Id::Other)
+                    .map_err(de::Error::custom),
+            };
+        }
+
+        let value = Value::deserialize(deserializer)?;
+
+        // Numbers outside the i64 range are never fractional
+        if let Some(n) = value.as_f64().filter(|_| value.is_f64()) {
+            if (i64::MIN as f64..i64::MAX as f64).contains(&n) {
+                if n as i64 as f64 != n {
+                    return Err(de::Error::invalid_value(
+                        de::Unexpected::Float(n),
+                        &"an id Number without a fractional part",
+                    ));
+                }
+                return Ok(Self::Number(n as i64));
+            }
+        }
+
+        Ok(Id::from(value))
+    }
+}
+
+/// An [`Id`] in a binary format, which can't tell a Number from a String
+/// without a tag. [`Id::Other`] is kept as its JSON text.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Id")]
+enum BinaryId<'a> {
+    Number(i64),
+    Str(Cow<'a, str>),
+    Other(String),
+}
+
+impl<'a> From<&'a Id> for BinaryId<'a> {
+    fn from(id: &'a Id) -> Self {
+        match id {
+            Id::Number(n) => Self::Number(*n),
+            Id::Str(s) => Self::Str(Cow::Borrowed(s)),
+            Id::Other(v) => Self::Other(v.to_string()),
+        }
+    }
+}
+
+/// The largest integer exactly representable as a double (2^53 - 1).
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// An [`Id`] safe to send to peers parsing Numbers as doubles (e.g.
+/// JavaScript), which silently lose precision above 2^53.
+///
+/// Numbers outside of ±(2^53 - 1) are serialized as Strings, and such
+/// Strings are deserialized back as Numbers.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Default)]
+pub struct JsSafeId(pub Id);
+
+impl From<Id> for JsSafeId {
+    fn from(id: Id) -> Self {
+        Self(id)
+    }
+}
+
+impl From<JsSafeId> for Id {
+    fn from(id: JsSafeId) -> Self {
+        id.0
+    }
+}
+
+impl Serialize for JsSafeId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match &self.0 {
+            // only text formats may be parsed by JavaScript
+            Id::Number(n)
+                if serializer.is_human_readable()
+                    && !(-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(n) =>
+            {
+                serializer.collect_str(n)
+            }
+            id => id.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for JsSafeId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return Id::deserialize(deserializer).map(Self);
+        }
+
+        let id = match Id::deserialize(deserializer)? {
+            Id::Str(s) => match s.parse::<i64>() {
+                Ok(n) if !(-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&n) => Id::Number(n),
+                _ => Id::Str(s),
+            },
+            id => id,
+        };
+
+        Ok(Self(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        test_utils::{positional, snapshot},
+        Request, Response, ResponseContent,
+    };
+
+    #[test]
+    fn test_id_serde() {
+        for (id, json) in [
+            (Id::Number(1), "1"),
+            (Id::Number(-1), "-1"),
+            (Id::Str("abc-123".to_string()), "\"abc-123\""),
+        ] {
+            assert_eq!(serde_json::to_string(&id).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Id>(json).unwrap(), id);
+        }
+
+        for json in ["true", "[1]", r#"{"a":1}"#, &u64::MAX.to_string()] {
+            let id = serde_json::from_str::<Id>(json).unwrap();
+
+            assert!(matches!(id, Id::Other(_)), "{json}");
+            assert_eq!(serde_json::to_string(&id).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn test_id_binary_format() {
+        for id in [
+            Id::Number(1),
+            Id::Str("1".to_string()),
+            Id::Other(Value::Bool(true)),
+            Id::Other(Value::Null),
+        ] {
+            assert_eq!(positional::round_trip(&id).unwrap(), id);
+        }
+
+        let id = JsSafeId(Id::Number(i64::MAX));
+        assert_eq!(positional::round_trip(&id).unwrap(), id);
+    }
+
+    #[test]
+    fn test_id_untagged() {
+        snapshot!(Id::Number(1));
+        snapshot!(Id::Str("x".to_string()));
+        snapshot!(Id::Other(Value::Bool(true)));
+        snapshot!(None::<Id>);
+
+        for (id, value) in [
+            (Id::Number(1), Value::from(1)),
+            (Id::Str("x".to_string()), Value::from("x")),
+            (Id::Other(Value::Bool(true)), Value::Bool(true)),
+        ] {
+            assert_eq!(serde_json::to_value(&id).unwrap(), value);
+            assert_eq!(serde_json::from_value::<Id>(value).unwrap(), id);
+        }
+        assert_eq!(serde_json::to_value(None::<Id>).unwrap(), Value::Null);
+        assert_eq!(
+            serde_json::from_value::<Option<Id>>(Value::Null).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_id_float() {
+        for (json, id) in [("1.0", 1), ("-2.0", -2), ("0.0", 0), ("1e3", 1000)] {
+            assert_eq!(serde_json::from_str::<Id>(json).unwrap(), Id::Number(id));
+        }
+
+        let error = serde_json::from_str::<Id>("1.5").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("invalid value: floating point `1.5`, expected an id Number"));
+
+        let json = r#"{"jsonrpc":"2.0","method":"m","id":1.5}"#;
+        assert!(serde_json::from_str::<Request<()>>(json).is_err());
+    }
+
+    #[test]
+    fn test_id_accessors() {
+        assert_eq!(Id::from(1).as_number(), Some(1));
+        assert_eq!(Id::from(1).as_str(), None);
+        assert_eq!(Id::from("1").as_number(), None);
+        assert_eq!(Id::from("1").as_str(), Some("1"));
+        assert_eq!(Id::Other(Value::Bool(true)).as_number(), None);
+        assert_eq!(Id::Other(Value::Bool(true)).as_str(), None);
+    }
+
+    #[test]
+    fn test_id_display() {
+        assert_eq!(Id::from(1).to_string(), "1");
+        assert_eq!(Id::from("a\"b").to_string(), r#""a\"b""#);
+        assert_eq!(Id::Other(Value::Bool(true)).to_string(), "true");
+    }
+
+    #[test]
+    fn test_id_absent_vs_null() {
+        let request = Request::new("m", (), None::<Id>);
+        let response = Response::new(ResponseContent::<(), ()>::Result(()), None::<Id>);
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap().get("id"),
+            None,
+            "a Request without an id is a Notification"
+        );
+        assert_eq!(
+            serde_json::to_value(&response).unwrap().get("id"),
+            Some(&Value::Null),
+            "a Response always has an id, possibly Null"
+        );
+        assert!(
+            serde_json::from_str::<Response<(), ()>>(r#"{"jsonrpc":"2.0","result":null}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_id_other_in_response() {
+        let json = r#"{"jsonrpc":"2.0","result":1,"id":true}"#;
+        let response: Response<i32, ()> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.id, Some(Id::Other(Value::Bool(true))));
+        assert_eq!(serde_json::to_string(&response).unwrap(), json);
+    }
+
+    #[test]
+    fn test_id_from() {
+        assert_eq!(Id::from(1u64), Id::Number(1));
+        assert_eq!(Id::from(u64::MAX), Id::Other(Value::from(u64::MAX)));
+        assert_eq!(
+            serde_json::from_str::<Id>(&u64::MAX.to_string()).unwrap(),
+            Id::from(u64::MAX)
+        );
+        assert_eq!(
+            serde_json::to_string(&Id::from(u64::MAX)).unwrap(),
+            u64::MAX.to_string()
+        );
+        assert_eq!(Id::from("abc".to_string()), Id::Str("abc".to_string()));
+    }
+
+    #[test]
+    fn test_id_default() {
+        assert_eq!(Id::default(), Id::Number(0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_id_ref_hash() {
+        use std::collections::{hash_map::DefaultHasher, BTreeMap, HashMap};
+
+        fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let other = Value::Bool(true);
+        for (id, id_ref) in [
+            (Id::from(1), IdRef::Number(1)),
+            (Id::from("abc"), IdRef::Str("abc")),
+            (Id::Other(other.clone()), IdRef::Other(&other)),
+        ] {
+            assert_eq!(hash(&id), hash(&id_ref));
+            assert_eq!(hash(&id), hash(&id_ref as &dyn AsIdRef));
+            assert_eq!(id.as_id_ref(), id_ref);
+            assert_eq!(Id::from(id_ref), id);
+        }
+
+        let map = HashMap::from([(Id::from("abc"), 1), (Id::from(2), 2)]);
+        assert_eq!(map.get(&IdRef::Str("abc") as &dyn AsIdRef), Some(&1));
+        assert_eq!(map.get(&IdRef::Number(2) as &dyn AsIdRef), Some(&2));
+        assert_eq!(map.get(&IdRef::Str("2") as &dyn AsIdRef), None);
+
+        let map = BTreeMap::from([(Id::from("abc"), 1), (Id::from(2), 2)]);
+        assert_eq!(map.get(&IdRef::Str("abc") as &dyn AsIdRef), Some(&1));
+    }
+
+    #[test]
+    fn test_js_safe_id() {
+        let unsafe_id: i64 = (1 << 53) + 1;
+
+        for (id, json) in [
+            (Id::from(unsafe_id), r#""9007199254740993""#),
+            (Id::from(-unsafe_id), r#""-9007199254740993""#),
+            (Id::from(MAX_SAFE_INTEGER), "9007199254740991"),
+            (Id::from("123"), r#""123""#),
+        ] {
+            let id = JsSafeId(id);
+
+            assert_eq!(serde_json::to_string(&id).unwrap(), json);
+            assert_eq!(serde_json::from_str::<JsSafeId>(json).unwrap(), id);
+        }
+
+        let request = Request::new("m", (), Some(JsSafeId(Id::from(unsafe_id))));
+        let json = serde_json::to_string(&request).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"jsonrpc":"2.0","method":"m","params":null,"id":"9007199254740993"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Request<(), JsSafeId>>(&json).unwrap(),
+            request
+        );
+    }
+
+    #[test]
+    fn test_id_ord() {
+        let mut ids = vec![
+            Id::from("b"),
+            Id::from(2),
+            Id::from("a"),
+            Id::from(-1),
+            Id::from("10"),
+            Id::Other(Value::Bool(true)),
+        ];
+        ids.sort();
+
+        assert_eq!(
+            ids,
+            [
+                Id::from(-1),
+                Id::from(2),
+                Id::from("10"),
+                Id::from("a"),
+                Id::from("b"),
+                Id::Other(Value::Bool(true)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use jsonrpsee_types::{ErrorObject, ErrorObjectOwned};
+use serde_json::Value;
+
+use crate::{Error, ResponseError};
+
+/// The **data** of the `ErrorObject` is parsed into a [`Value`], a Null
+/// **data** being `None`.
+impl From<ErrorObject<'_>> for ResponseError<Value> {
+    fn from(error: ErrorObject<'_>) -> Self {
+        let mut response_error = ResponseError::new(error.code(), error.message().to_owned());
+        // a RawValue always holds valid JSON
+        response_error.data = error
+            .data()
+            .and_then(|data| serde_json::from_str::<Value>(data.get()).ok())
+            .filter(|data| !data.is_null());
+        response_error
+    }
+}
+
+/// Fails if the **code** doesn't fit in an `i32`. The **extra** members are
+/// dropped, since an `ErrorObject` can't hold them.
+impl TryFrom<ResponseError<Value>> for ErrorObjectOwned {
+    type Error = Error;
+
+    fn try_from(error: ResponseError<Value>) -> Result<Self, Self::Error> {
+        let code = i32::try_from(error.code)
+            .map_err(|_| Error::ErrorCodeOutOfRange { code: error.code })?;
+
+        Ok(ErrorObject::owned(code, error.message, error.data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use jsonrpsee_types::ErrorCode as JsonrpseeErrorCode;
+
+    use crate::ErrorCode;
+
+    #[test]
+    fn test_jsonrpsee_predefined_codes() {
+        for (code, jsonrpsee_code) in [
+            (ErrorCode::ParseError, JsonrpseeErrorCode::ParseError),
+            (
+                ErrorCode::InvalidRequest,
+                JsonrpseeErrorCode::InvalidRequest,
+            ),
+            (
+                ErrorCode::MethodNotFound,
+                JsonrpseeErrorCode::MethodNotFound,
+            ),
+            (ErrorCode::InvalidParams, JsonrpseeErrorCode::InvalidParams),
+            (ErrorCode::InternalError, JsonrpseeErrorCode::InternalError),
+        ] {
+            let error = ResponseError::<Value>::from(code);
+            let object = ErrorObjectOwned::try_from(error.clone()).unwrap();
+            assert_eq!(object.code(), jsonrpsee_code.code());
+            assert_eq!(object.message(), error.message);
+            assert_eq!(ResponseError::from(object), error);
+
+            let error = ResponseError::from(ErrorObject::from(jsonrpsee_code));
+            assert_eq!(error.code, i64::from(code));
+        }
+    }
+
+    #[test]
+    fn test_jsonrpsee_data() {
+        let error = ResponseError::invalid_params().with_data(serde_json::json!({"p0": [1, 2]}));
+        let object = ErrorObjectOwned::try_from(error.clone()).unwrap();
+
+        assert_eq!(object.data().unwrap().get(), r#"{"p0":[1,2]}"#);
+        assert_eq!(ResponseError::from(object), error);
+
+        let object = ErrorObject::owned(1, "m", Some(Value::Null));
+        assert_eq!(ResponseError::from(object), ResponseError::new(1, "m"));
+    }
+
+    #[test]
+    fn test_jsonrpsee_code_out_of_range() {
+        let error = ResponseError::<Value>::new(i64::from(i32::MAX) + 1, "m");
+
+        assert!(matches!(
+            ErrorObjectOwned::try_from(error),
+            Err(Error::ErrorCodeOutOfRange { code }) if code == i64::from(i32::MAX) + 1
+        ));
+    }
+}
@@ -49,28 +49,135 @@
 //! with a String value of "2.0" whereas 1.0 does not. Most 2.0 implementations
 //! should consider trying to handle 1.0 objects, even if not the peer-to-peer
 //! and class hinting aspects of 1.0.
+//!
+//! # Features
+//! - `std` (default): implements `std::error::Error` for the error types, and
+//!   adds `BatchReader` and `Router`.
+//!   Without it the crate is `no_std`, and only requires `alloc`:
+//!   ```toml
+//!   jsonrpc-types = { version = "0.1", default-features = false }
+//!   ```
+//! - `v1`: accepts objects without the **jsonrpc** member as JSON-RPC 1.0,
+//!   see [`Version`].
+//! - `codec`: adds `JsonRpcCodec`, which frames messages in a byte stream,
+//!   either line-delimited or with LSP-style `Content-Length` headers.
+//! - `tokio-util`: implements `tokio_util`'s `Decoder` and `Encoder` for
+//!   `JsonRpcCodec`. Implies `codec` and `std`.
+//! - `uuid`: adds `UuidGenerator`, which generates random UUID String ids.
+//! - `schemars`: implements `schemars::JsonSchema` for [`Request`],
+//!   [`Notification`], [`Response`], [`ResponseError`] and [`Id`], e.g. to
+//!   publish an OpenRPC document.
+//! - `jsonrpsee`: converts between [`ResponseError`] and the `ErrorObject`
+//!   of `jsonrpsee-types`. Implies `std`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+extern crate alloc;
+
+mod batch;
+#[cfg(feature = "codec")]
+mod codec;
+#[cfg(all(test, feature = "std"))]
+mod conformance;
+mod de;
+mod error;
+mod error_code;
+mod generator;
+mod id;
+#[cfg(feature = "jsonrpsee")]
+mod jsonrpsee;
+mod message;
+mod method;
 mod notification;
+mod null;
+mod params;
+pub mod prelude;
+#[cfg(feature = "std")]
+mod reader;
 mod request;
 mod response;
+#[cfg(feature = "std")]
+mod router;
+#[cfg(feature = "schemars")]
+mod schema;
+mod ser;
+mod version;
 
-pub use notification::Notification;
-pub use request::Request;
-pub use response::{Response, ResponseContent, ResponseError};
+pub use batch::Batch;
+#[cfg(feature = "codec")]
+pub use codec::{Framing, JsonRpcCodec};
+pub use error::Error;
+pub use error_code::{
+    ErrorCode, ErrorKind, INTERNAL_ERROR, INVALID_PARAMS, INVALID_REQUEST, METHOD_NOT_FOUND,
+    PARSE_ERROR, SERVER_ERROR_RANGE,
+};
+#[cfg(target_has_atomic = "64")]
+pub use generator::IdGenerator;
+#[cfg(feature = "uuid")]
+pub use generator::UuidGenerator;
+pub use id::{AsIdRef, Id, IdRef, JsSafeId};
+pub use message::{parse_message, parse_ndjson, Message};
+pub use method::{is_reserved_method, Method, MethodCall, MethodName};
+pub use notification::{Notification, OwnedNotification};
+pub use null::Null;
+pub use params::{LenientParams, NoParams, Params};
+#[cfg(feature = "std")]
+pub use reader::BatchReader;
+pub use request::{Handler, OwnedRequest, Request, RequestBuilder, RequestRef};
+#[cfg(feature = "std")]
+pub use response::IntoResponseError;
+pub use response::{
+    LenientResponseError, Response, ResponseBuilder, ResponseContent, ResponseError,
+};
+#[cfg(feature = "std")]
+pub use router::Router;
+pub use ser::IdFirst;
+pub use version::Version;
 
-pub(crate) const JSONRPC_V2: &str = "2.0";
+/// The value of the **jsonrpc** member of JSON-RPC 2.0 objects.
+pub const JSONRPC_VERSION: &str = "2.0";
 
 /// Helpers for serialization/deserialization tests
 #[cfg(test)]
 pub(crate) mod test_utils {
     use serde::{Deserialize, Serialize};
 
-    #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+    #[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
     pub(crate) struct Params {
         pub p0: u32,
         pub p1: u32,
     }
 
+    #[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+    pub(crate) struct NestedParams {
+        pub name: String,
+        pub params: Params,
+        pub list: Vec<Params>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub tags: Option<Vec<String>>,
+        pub child: Option<Box<NestedParams>>,
+    }
+
+    impl NestedParams {
+        /// Two levels of nesting, with both a present and an absent optional
+        /// field.
+        pub(crate) fn sample() -> Self {
+            Self {
+                name: "parent".to_string(),
+                params: Params { p0: 0, p1: 1 },
+                list: vec![Params { p0: 2, p1: 3 }, Params { p0: 4, p1: 5 }],
+                tags: Some(vec!["a".to_string(), "b".to_string()]),
+                child: Some(Box::new(Self {
+                    name: "child".to_string(),
+                    params: Params { p0: 6, p1: 7 },
+                    list: vec![],
+                    tags: None,
+                    child: None,
+                })),
+            }
+        }
+    }
+
     #[cfg(test)]
     macro_rules! snapshot {
         ($e:expr) => {
@@ -85,4 +192,6 @@ pub(crate) mod test_utils {
 
     #[cfg(test)]
     pub(crate) use snapshot;
+
+    pub(crate) mod positional;
 }
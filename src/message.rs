@@ -0,0 +1,318 @@
+use serde::{
+    de::{self, DeserializeOwned},
+    Deserialize, Serialize,
+};
+use serde_json::{Map, Value};
+
+use crate::{Error, Id, Notification, Request, Response};
+
+/// Any JSON-RPC object sent between a Client and a Server.
+///
+/// Deserialization dispatches on the members present in the object:
+///
+/// | members | variant |
+/// |---------|---------|
+/// | **method** and **id** | [`Message::Request`] |
+/// | **method** without **id** | [`Message::Notification`] |
+/// | **result** or **error** | [`Message::Response`] |
+///
+/// Binary formats can't dispatch on the members present, so messages are
+/// tagged with their variant in them.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Message<'a, P, R, E, I = Id> {
+    Request(Request<'a, P, I>),
+    Notification(Notification<'a, P>),
+    Response(Response<R, E, I>),
+}
+
+impl<P, R, E, I> Message<'_, P, R, E, I> {
+    /// The **id** of a Request or Response, `None` for a Notification or a
+    /// Null id.
+    pub fn id(&self) -> Option<&I> {
+        match self {
+            Message::Request(request) => request.id.as_ref(),
+            Message::Notification(_) => None,
+            Message::Response(response) => response.id.as_ref(),
+        }
+    }
+}
+
+/// Parse any JSON-RPC object, keeping its **params**, **result** and
+/// **data** as [`Value`]s.
+///
+/// Never panics, even for arbitrary bytes, which makes it a suitable fuzzing
+/// entry point.
+pub fn parse_message(bytes: &[u8]) -> Result<Message<'static, Value, Value, Value>, Error> {
+    Ok(serde_json::from_slice(bytes)?)
+}
+
+/// Parse newline-delimited messages, as in a log of JSON-RPC messages.
+///
+/// Blank lines are skipped, every other line is parsed as a message.
+pub fn parse_ndjson<P, R, E, I>(
+    input: &str,
+) -> impl Iterator<Item = Result<Message<'static, P, R, E, I>, Error>> + '_
+where
+    P: DeserializeOwned,
+    R: DeserializeOwned,
+    E: DeserializeOwned,
+    I: DeserializeOwned,
+{
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Ok(serde_json::from_str(line)?))
+}
+
+impl<P: Serialize, R: Serialize, E: Serialize, I: Serialize> Serialize for Message<'_, P, R, E, I> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if !serializer.is_human_readable() {
+            return BinaryMessage::from(self).serialize(serializer);
+        }
+
+        match self {
+            Message::Request(request) => request.serialize(serializer),
+            Message::Notification(notification) => notification.serialize(serializer),
+            Message::Response(response) => response.serialize(serializer),
+        }
+    }
+}
+
+/// A [`Message`] in a binary format, tagged with its variant.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Message")]
+enum BinaryMessage<T, N, S> {
+    Request(T),
+    Notification(N),
+    Response(S),
+}
+
+impl<'m, 'a, P, R, E, I> From<&'m Message<'a, P, R, E, I>>
+    for BinaryMessage<&'m Request<'a, P, I>, &'m Notification<'a, P>, &'m Response<R, E, I>>
+{
+    fn from(message: &'m Message<'a, P, R, E, I>) -> Self {
+        match message {
+            Message::Request(request) => Self::Request(request),
+            Message::Notification(notification) => Self::Notification(notification),
+            Message::Response(response) => Self::Response(response),
+        }
+    }
+}
+
+impl<'de, P, R, E, I> Deserialize<'de> for Message<'_, P, R, E, I>
+where
+    P: Deserialize<'de>,
+    R: Deserialize<'de>,
+    E: Deserialize<'de>,
+    I: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return Ok(match BinaryMessage::deserialize(deserializer)? {
+                BinaryMessage::Request(request) => Message::Request(request),
+                BinaryMessage::Notification(notification) => Message::Notification(notification),
+                BinaryMessage::Response(response) => Message::Response(response),
+            });
+        }
+
+        let object = Map::deserialize(deserializer)?;
+
+        let message = if object.contains_key("method") {
+            if object.contains_key("id") {
+                Request::deserialize(Value::Object(object)).map(Message::Request)
+            } else {
+                Notification::deserialize(Value::Object(object)).map(Message::Notification)
+            }
+        } else if object.contains_key("result") || object.contains_key("error") {
+            Response::deserialize(Value::Object(object)).map(Message::Response)
+        } else {
+            return Err(de::Error::custom(
+                "expected a Request, Notification or Response object",
+            ));
+        };
+
+        message.map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        test_utils::{positional, Params},
+        ResponseContent, ResponseError,
+    };
+
+    type TestMessage = Message<'static, Params, i32, ()>;
+
+    #[test]
+    fn test_message_request() {
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":{"p0":0,"p1":1},"id":1}"#;
+
+        assert_eq!(
+            serde_json::from_str::<TestMessage>(json).unwrap(),
+            Message::Request(Request::new("m", Params { p0: 0, p1: 1 }, Some(1.into())))
+        );
+    }
+
+    #[test]
+    fn test_message_notification() {
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":{"p0":0,"p1":1}}"#;
+
+        assert_eq!(
+            serde_json::from_str::<TestMessage>(json).unwrap(),
+            Message::Notification(Notification::new("m", Params { p0: 0, p1: 1 }))
+        );
+    }
+
+    #[test]
+    fn test_message_response() {
+        let json = r#"{"jsonrpc":"2.0","result":1,"id":1}"#;
+
+        assert_eq!(
+            serde_json::from_str::<TestMessage>(json).unwrap(),
+            Message::Response(Response::new(ResponseContent::Result(1), Some(1.into())))
+        );
+
+        let json =
+            r#"{"jsonrpc":"2.0","error":{"code":-32601,"message":"Method not found"},"id":null}"#;
+
+        assert_eq!(
+            serde_json::from_str::<TestMessage>(json).unwrap(),
+            Message::Response(Response::new(
+                ResponseContent::Error(ResponseError::method_not_found()),
+                None
+            ))
+        );
+    }
+
+    #[test]
+    fn test_message_id() {
+        let request: TestMessage =
+            Message::Request(Request::new("m", Params { p0: 0, p1: 1 }, Some(1.into())));
+        let notification: TestMessage =
+            Message::Notification(Notification::new("m", Params { p0: 0, p1: 1 }));
+        let response: TestMessage =
+            Message::Response(Response::new(ResponseContent::Result(1), Some(2.into())));
+        let null_id: TestMessage = Message::Response(Response::new(
+            ResponseContent::Error(ResponseError::parse_error()),
+            None,
+        ));
+
+        assert_eq!(request.id(), Some(&Id::from(1)));
+        assert_eq!(notification.id(), None);
+        assert_eq!(response.id(), Some(&Id::from(2)));
+        assert_eq!(null_id.id(), None);
+    }
+
+    #[test]
+    fn test_message_binary_format() {
+        for message in [
+            Message::Request(Request::new("m", Params { p0: 0, p1: 1 }, Some(1.into()))),
+            Message::Notification(Notification::new("m", Params { p0: 0, p1: 1 })),
+            Message::Response(Response::new(ResponseContent::Result(1), None)),
+            Message::Response(Response::new(
+                ResponseContent::Error(ResponseError::parse_error()),
+                None,
+            )),
+        ] {
+            let message: TestMessage = message;
+            assert_eq!(positional::round_trip(&message).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn test_message_invalid() {
+        for json in [
+            r#"{"jsonrpc":"2.0","id":1}"#,
+            r#"{"jsonrpc":"2.0","method":1,"id":1}"#,
+            r#"[1, 2]"#,
+            r#""garbage""#,
+        ] {
+            assert!(serde_json::from_str::<TestMessage>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_message() {
+        let json = br#"{"jsonrpc":"2.0","method":"m","params":{"a":[1,2.5,null]},"id":1.0}"#;
+
+        assert!(matches!(parse_message(json), Ok(Message::Request(_))));
+        for len in 0..json.len() {
+            assert!(parse_message(&json[..len]).is_err());
+        }
+
+        for garbage in [
+            &b"\xff\xfe\x00"[..],
+            b"{\"jsonrpc\":\"2.0\",\"method\":\"\xc3\x28\"}",
+            b"{\"jsonrpc\":\"2.0\",\"result\":1,\"id\":1e999}",
+            b"{\"jsonrpc\":\"2.0\",\"result\":1,\"id\":-1.5}",
+            b"{\"jsonrpc\":\"2.0\",\"error\":{\"code\":1.5},\"id\":1}",
+            &[b'['; 1000][..],
+            b"[]",
+            b"null",
+        ] {
+            assert!(parse_message(garbage).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_message_absent_params() {
+        let message = parse_message(br#"{"jsonrpc": "2.0", "method": "foobar", "id": "1"}"#);
+        assert!(matches!(
+            message,
+            Ok(Message::Request(request)) if request.params == Value::Null
+        ));
+
+        let message = parse_message(br#"{"jsonrpc": "2.0", "method": "foobar"}"#);
+        assert!(matches!(
+            message,
+            Ok(Message::Notification(notification)) if notification.params == Value::Null
+        ));
+    }
+
+    #[test]
+    fn test_parse_ndjson() {
+        let input = r#"{"jsonrpc":"2.0","method":"m","params":{"p0":0,"p1":1},"id":1}
+
+{"jsonrpc":"2.0","method":"m","params":{"p0":0,"p1":1}}
+{"jsonrpc":"2.0","result":1,"id":1}
+"#;
+
+        let messages = parse_ndjson::<Params, i32, (), Id>(input)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            messages,
+            [
+                Message::Request(Request::new("m", Params { p0: 0, p1: 1 }, Some(1.into()))),
+                Message::Notification(Notification::new("m", Params { p0: 0, p1: 1 })),
+                Message::Response(Response::new(ResponseContent::Result(1), Some(1.into()))),
+            ]
+        );
+
+        let mut messages = parse_ndjson::<Params, i32, (), Id>(
+            "garbage\n{\"jsonrpc\":\"2.0\",\"result\":1,\"id\":1}",
+        );
+        assert!(matches!(messages.next(), Some(Err(Error::Json(_)))));
+        assert!(matches!(messages.next(), Some(Ok(Message::Response(_)))));
+        assert!(messages.next().is_none());
+    }
+
+    #[test]
+    fn test_message_round_trip() {
+        let message: TestMessage =
+            Message::Request(Request::new("m", Params { p0: 0, p1: 1 }, Some(1.into())));
+        let json = serde_json::to_string(&message).unwrap();
+
+        assert_eq!(serde_json::from_str::<TestMessage>(&json).unwrap(), message);
+    }
+}
@@ -0,0 +1,247 @@
+use alloc::{borrow::Cow, string::String};
+use core::{fmt, ops::Deref};
+
+use serde::{de, Deserialize, Serialize};
+
+use crate::{Error, Notification, Request, RequestRef};
+
+/// Whether `name` is reserved for rpc-internal methods and extensions.
+///
+/// Method names that begin with the word rpc followed by a period character
+/// (U+002E or ASCII 46) are reserved for rpc-internal methods and extensions
+/// and MUST NOT be used for anything else.
+pub fn is_reserved_method(name: &str) -> bool {
+    name.starts_with("rpc.")
+}
+
+/// A validated **method** name, which is never empty.
+///
+/// Converts into the **method** of a [`Request`] or [`Notification`]:
+///
+/// ```
+/// use jsonrpc_types::{Id, MethodName, Request};
+///
+/// let subtract = MethodName::new("subtract")?;
+/// let request = Request::new(subtract.clone(), [42, 23], Some(Id::from(1)));
+///
+/// assert_eq!(request.method, *subtract);
+/// assert!(MethodName::new("").is_err());
+/// assert!(MethodName::new_checked("rpc.discover").is_err());
+/// # Ok::<(), jsonrpc_types::Error>(())
+/// ```
+#[derive(Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+#[serde(transparent)]
+pub struct MethodName<'a>(Cow<'a, str>);
+
+impl<'a> MethodName<'a> {
+    /// Fails if `name` is empty.
+    ///
+    /// Reserved names are accepted, to invoke rpc-internal methods on purpose.
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Result<Self, Error> {
+        let name = name.into();
+        if name.is_empty() {
+            return Err(Error::EmptyMethod);
+        }
+
+        Ok(Self(name))
+    }
+
+    /// Like [`MethodName::new`], but also fails if `name` is reserved (see
+    /// [`is_reserved_method`]).
+    pub fn new_checked(name: impl Into<Cow<'a, str>>) -> Result<Self, Error> {
+        let name = Self::new(name)?;
+        if name.is_reserved() {
+            return Err(Error::ReservedMethod {
+                method: name.0.into_owned(),
+            });
+        }
+
+        Ok(name)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Cow<'a, str> {
+        self.0
+    }
+
+    /// Whether the name is reserved, see [`is_reserved_method`].
+    pub fn is_reserved(&self) -> bool {
+        is_reserved_method(&self.0)
+    }
+}
+
+impl Deref for MethodName<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for MethodName<'_> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for MethodName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'a> From<MethodName<'a>> for Cow<'a, str> {
+    fn from(name: MethodName<'a>) -> Self {
+        name.0
+    }
+}
+
+impl<'a> TryFrom<&'a str> for MethodName<'a> {
+    type Error = Error;
+
+    fn try_from(name: &'a str) -> Result<Self, Self::Error> {
+        Self::new(name)
+    }
+}
+
+impl TryFrom<String> for MethodName<'_> {
+    type Error = Error;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        Self::new(name)
+    }
+}
+
+impl<'de> Deserialize<'de> for MethodName<'_> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::new(String::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// A method invocable on a Server, binding its name to its **params** and
+/// **result** types.
+pub trait Method {
+    /// The **method** member.
+    const NAME: &'static str;
+    /// The **params** member.
+    type Params;
+    /// The **result** member of a successful Response.
+    type Result;
+}
+
+/// A call of a method, either a [`Request`] or a [`Notification`].
+pub trait MethodCall {
+    /// The **params** member.
+    type Params;
+
+    /// The **method** member.
+    fn method(&self) -> &str;
+
+    /// The **params** member.
+    fn params(&self) -> &Self::Params;
+}
+
+impl<P, I> MethodCall for Request<'_, P, I> {
+    type Params = P;
+
+    fn method(&self) -> &str {
+        &self.method
+    }
+
+    fn params(&self) -> &P {
+        &self.params
+    }
+}
+
+impl<P, I> MethodCall for RequestRef<'_, P, I> {
+    type Params = P;
+
+    fn method(&self) -> &str {
+        self.method
+    }
+
+    fn params(&self) -> &P {
+        self.params
+    }
+}
+
+impl<P> MethodCall for Notification<'_, P> {
+    type Params = P;
+
+    fn method(&self) -> &str {
+        &self.method
+    }
+
+    fn params(&self) -> &P {
+        &self.params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Id;
+
+    #[test]
+    fn test_is_reserved_method() {
+        for name in ["rpc.", "rpc.discover", "rpc.foo.bar"] {
+            assert!(is_reserved_method(name), "{name}");
+        }
+        for name in ["rpc", "rpcfoo", "foo.rpc.bar", "RPC.foo", "subtract"] {
+            assert!(!is_reserved_method(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_method_name() {
+        let name = MethodName::new("subtract").unwrap();
+        assert_eq!(&*name, "subtract");
+        assert!(!name.is_reserved());
+        assert_eq!(MethodName::new_checked("subtract").unwrap(), name);
+        assert_eq!(
+            Notification::new(name, ()),
+            Notification::new("subtract", ())
+        );
+
+        assert!(matches!(MethodName::new(""), Err(Error::EmptyMethod)));
+        assert!(matches!(
+            MethodName::new_checked(""),
+            Err(Error::EmptyMethod)
+        ));
+
+        assert!(MethodName::new("rpc.discover").unwrap().is_reserved());
+        assert!(matches!(
+            MethodName::new_checked("rpc.discover"),
+            Err(Error::ReservedMethod { method }) if method == "rpc.discover"
+        ));
+    }
+
+    #[test]
+    fn test_method_name_serde() {
+        let name: MethodName = serde_json::from_str(r#""subtract""#).unwrap();
+        assert_eq!(name.as_str(), "subtract");
+        assert_eq!(serde_json::to_string(&name).unwrap(), r#""subtract""#);
+
+        assert!(serde_json::from_str::<MethodName>(r#""""#).is_err());
+    }
+
+    #[test]
+    fn test_method_call() {
+        fn log<M: MethodCall<Params = Vec<i32>>>(lines: &mut Vec<String>, call: &M) {
+            lines.push(format!("{}{:?}", call.method(), call.params()));
+        }
+
+        let mut lines = Vec::new();
+        log(&mut lines, &Request::new("a", vec![1], Some(Id::from(1))));
+        log(&mut lines, &Notification::new("b", vec![2, 3]));
+
+        assert_eq!(lines, ["a[1]", "b[2, 3]"]);
+    }
+}
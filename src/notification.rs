@@ -1,6 +1,13 @@
-use serde::{ser::SerializeStruct, Deserialize, Serialize};
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
 
-use crate::JSONRPC_V2;
+use serde::{de::DeserializeOwned, ser::SerializeStruct, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    de::{self, Absent, CowStr},
+    is_reserved_method, ser, Error, Id, Method, Request, Version,
+};
 
 /// A Notification is a Request object without an "id" member.
 ///
@@ -15,39 +22,260 @@ use crate::JSONRPC_V2;
 /// do not have a Response object to be returned. As such, the
 /// Client would not be aware of any errors (like e.g. "Invalid
 /// params","Internal error").
-#[derive(Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
-pub struct Notification<P> {
-    pub method: String,
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Notification<'a, P> {
+    /// The **jsonrpc** member, [`Version::V1`] omits it.
+    pub jsonrpc: Version,
+    pub method: Cow<'a, str>,
+    /// **params** is omitted if it serializes as `None` (e.g. `Option::None`).
+    ///
+    /// An absent **params** member is deserialized like a Null one, which
+    /// `()`, `Option`, `Value`, [`Params`](crate::Params) and
+    /// [`NoParams`](crate::NoParams) accept. Params requiring a value (e.g.
+    /// a `Vec` or a struct) fail with a missing field error.
     pub params: P,
 }
 
-impl<P> Notification<P> {
-    pub fn new(method: impl Into<String>, params: P) -> Self {
+/// A [`Notification`] owning its **method**.
+pub type OwnedNotification<P> = Notification<'static, P>;
+
+impl<'a, P> Notification<'a, P> {
+    pub fn new(method: impl Into<Cow<'a, str>>, params: P) -> Self {
         Self {
+            jsonrpc: Version::V2,
             method: method.into(),
             params,
         }
     }
+
+    /// Like [`Notification::new`], but fails if the **method** name is
+    /// reserved (see [`is_reserved_method`]).
+    ///
+    /// Use [`Notification::new`] to invoke a reserved method on purpose.
+    pub fn try_new(method: impl Into<Cow<'a, str>>, params: P) -> Result<Self, Error> {
+        let method = method.into();
+        if is_reserved_method(&method) {
+            return Err(Error::ReservedMethod {
+                method: method.into_owned(),
+            });
+        }
+
+        Ok(Self::new(method, params))
+    }
+
+    /// Turn the Notification into a [`Request`] with the given **id**.
+    pub fn with_id(self, id: impl Into<Id>) -> Request<'a, P> {
+        Request {
+            jsonrpc: self.jsonrpc,
+            ..Request::new(self.method, self.params, Some(id.into()))
+        }
+    }
+
+    /// Like converting `From` a [`Request`], but clones the **method** and
+    /// **params** so that the Request can still be used, e.g. to replay it to
+    /// a subscriber.
+    pub fn from_request_ref<I>(request: &Request<'a, P, I>) -> Self
+    where
+        P: Clone,
+    {
+        Self {
+            jsonrpc: request.jsonrpc,
+            method: request.method.clone(),
+            params: request.params.clone(),
+        }
+    }
+
+    /// Deserialize a Notification, borrowing the **method** from the input
+    /// when possible, see [`Request::deserialize_borrowed`].
+    pub fn deserialize_borrowed<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+        P: Deserialize<'a>,
+    {
+        if !deserializer.is_human_readable() {
+            let BinaryNotification {
+                method: CowStr(method),
+                params,
+            } = BinaryNotification::deserialize(deserializer)?;
+            return Ok(Self::new(method, params));
+        }
+
+        let RawNotification {
+            jsonrpc,
+            method: CowStr(method),
+            params,
+        } = RawNotification::deserialize(deserializer)?;
+
+        Ok(Self {
+            jsonrpc: match jsonrpc {
+                Some(jsonrpc) => jsonrpc,
+                None => de::missing_version()?,
+            },
+            method,
+            params: params.into_present()?,
+        })
+    }
+
+    pub fn into_owned(self) -> OwnedNotification<P> {
+        Notification {
+            jsonrpc: self.jsonrpc,
+            method: Cow::Owned(self.method.into_owned()),
+            params: self.params,
+        }
+    }
+}
+
+impl<P> Notification<'static, P> {
+    /// Create a Notification invoking `M`.
+    pub fn for_method<M: Method<Params = P>>(params: P) -> Self {
+        Self::new(M::NAME, params)
+    }
+}
+
+/// Drops the **id** of the [`Request`].
+impl<'a, P, I> From<Request<'a, P, I>> for Notification<'a, P> {
+    fn from(request: Request<'a, P, I>) -> Self {
+        Self {
+            jsonrpc: request.jsonrpc,
+            method: request.method,
+            params: request.params,
+        }
+    }
 }
 
-impl<P: Serialize> Serialize for Notification<P> {
+/// A concise summary for logging, e.g. `notification "update"`.
+impl<P> fmt::Display for Notification<'_, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "notification {:?}", self.method)
+    }
+}
+
+impl<P: Serialize> Serialize for Notification<'_, P> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
+        if !serializer.is_human_readable() {
+            // see `Request::serialize_ordered`
+            let mut state = serializer.serialize_struct("Notification", 2)?;
+            state.serialize_field("method", &self.method)?;
+            state.serialize_field("params", &self.params)?;
+            return state.end();
+        }
+
         let mut state = serializer.serialize_struct("Notification", 3)?;
-        state.serialize_field("jsonrpc", JSONRPC_V2)?;
+        if ser::is_none(&self.jsonrpc) {
+            state.skip_field("jsonrpc")?;
+        } else {
+            state.serialize_field("jsonrpc", &self.jsonrpc)?;
+        }
         state.serialize_field("method", &self.method)?;
-        state.serialize_field("params", &self.params)?;
+        if ser::is_none(&self.params) {
+            state.skip_field("params")?;
+        } else {
+            state.serialize_field("params", &self.params)?;
+        }
         state.end()
     }
 }
 
+#[derive(Deserialize)]
+#[serde(rename = "Notification")]
+struct RawNotification<M, P> {
+    #[serde(default, deserialize_with = "de::some")]
+    jsonrpc: Option<Version>,
+    method: M,
+    #[serde(default = "Absent::default")]
+    params: Absent<P>,
+}
+
+/// A Notification in a binary format, see [`Version`].
+#[derive(Deserialize)]
+#[serde(rename = "Notification")]
+struct BinaryNotification<M, P> {
+    method: M,
+    params: P,
+}
+
+impl<'de, P: Deserialize<'de>> Deserialize<'de> for Notification<'_, P> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            let BinaryNotification { method, params } =
+                BinaryNotification::<String, _>::deserialize(deserializer)?;
+            return Ok(Self::new(method, params));
+        }
+
+        let RawNotification {
+            jsonrpc,
+            method,
+            params,
+        } = RawNotification::<String, _>::deserialize(deserializer)?;
+
+        Ok(Self {
+            jsonrpc: match jsonrpc {
+                Some(jsonrpc) => jsonrpc,
+                None => de::missing_version()?,
+            },
+            method: Cow::Owned(method),
+            params: params.into_present()?,
+        })
+    }
+}
+
+impl<P: DeserializeOwned> TryFrom<Value> for Notification<'_, P> {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+impl<P> Notification<'_, P> {
+    /// Parse a Notification from a JSON string.
+    ///
+    /// ```
+    /// use jsonrpc_types::Notification;
+    ///
+    /// let json = r#"{"jsonrpc":"2.0","method":"update","params":[1,2,3,4,5]}"#;
+    /// let notification = Notification::<Vec<i32>>::from_json_str(json)?;
+    ///
+    /// assert_eq!(notification.params, [1, 2, 3, 4, 5]);
+    /// assert_eq!(notification.to_json_string()?, json);
+    /// # Ok::<(), jsonrpc_types::Error>(())
+    /// ```
+    pub fn from_json_str(s: &str) -> Result<Self, Error>
+    where
+        P: DeserializeOwned,
+    {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// Like [`Notification::from_json_str`], but fails on members other than
+    /// **jsonrpc**, **method** and **params**.
+    pub fn from_json_str_strict(s: &str) -> Result<Self, Error>
+    where
+        P: DeserializeOwned,
+    {
+        de::from_str_strict(s, &["jsonrpc", "method", "params"])
+    }
+
+    /// Serialize the Notification as a JSON string.
+    pub fn to_json_string(&self) -> Result<String, Error>
+    where
+        P: Serialize,
+    {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::test_utils::{snapshot, Params};
+    use crate::test_utils::{positional, snapshot, NestedParams, Params};
 
     #[test]
     fn test_notification_serde() {
@@ -55,4 +283,145 @@ mod tests {
         snapshot!(Notification::new("method", vec![0, 1]));
         snapshot!(Notification::new("method", Params { p0: 0, p1: 1 }));
     }
+
+    #[test]
+    fn test_notification_nested_params() {
+        snapshot!(Notification::new("method", NestedParams::sample()));
+    }
+
+    #[test]
+    fn test_notification_display() {
+        assert_eq!(
+            Notification::new("update", [1, 2]).to_string(),
+            r#"notification "update""#
+        );
+    }
+
+    #[test]
+    fn test_notification_absent_params() {
+        snapshot!(Notification::new("method", None::<()>));
+
+        let json = r#"{"jsonrpc":"2.0","method":"m"}"#;
+        let notification: Notification<Option<Params>> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(notification.params, None);
+        assert_eq!(serde_json::to_string(&notification).unwrap(), json);
+    }
+
+    #[test]
+    fn test_notification_unit_params() {
+        for json in [
+            r#"{"jsonrpc":"2.0","method":"m"}"#,
+            r#"{"jsonrpc":"2.0","method":"m","params":null}"#,
+        ] {
+            let notification: Notification<()> = serde_json::from_str(json).unwrap();
+            assert_eq!(notification, Notification::new("m", ()));
+        }
+
+        let json = r#"{"jsonrpc":"2.0","method":"m"}"#;
+        let notification: Notification<Value> = serde_json::from_str(json).unwrap();
+        assert_eq!(notification.params, Value::Null);
+        assert!(serde_json::from_str::<Notification<Vec<i32>>>(json).is_err());
+    }
+
+    #[test]
+    fn test_notification_binary_format() {
+        for notification in [
+            Notification::new("m", vec![1]),
+            Notification::new("m", vec![]),
+        ] {
+            assert_eq!(positional::round_trip(&notification).unwrap(), notification);
+        }
+    }
+
+    #[test]
+    fn test_notification_try_new() {
+        assert_eq!(
+            Notification::try_new("m", ()).unwrap(),
+            Notification::new("m", ())
+        );
+        assert!(matches!(
+            Notification::try_new("rpc.m", ()),
+            Err(Error::ReservedMethod { method }) if method == "rpc.m"
+        ));
+    }
+
+    #[test]
+    fn test_notification_strict() {
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":[],"extra":true}"#;
+
+        assert!(Notification::<Vec<i32>>::from_json_str(json).is_ok());
+        assert!(matches!(
+            Notification::<Vec<i32>>::from_json_str_strict(json),
+            Err(Error::UnknownMember { member }) if member == "extra"
+        ));
+        assert!(Notification::<Vec<i32>>::from_json_str_strict(
+            r#"{"jsonrpc":"2.0","method":"m","params":[]}"#
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_notification_invalid_version() {
+        for json in [
+            r#"{"jsonrpc":"1.0","method":"m","params":null}"#,
+            r#"{"jsonrpc":2.0,"method":"m","params":null}"#,
+        ] {
+            assert!(serde_json::from_str::<Notification<()>>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn test_notification_request_conversion() {
+        let request = Request::new("method", Params { p0: 0, p1: 1 }, Some(1.into()));
+        let notification = Notification::from(request.clone());
+
+        assert_eq!(
+            notification,
+            Notification::new("method", Params { p0: 0, p1: 1 })
+        );
+        assert_eq!(notification.with_id(1), request);
+    }
+
+    #[test]
+    fn test_notification_from_request_ref() {
+        let request = Request::new("method", Params { p0: 0, p1: 1 }, Some(Id::from(1)));
+        let notification = Notification::from_request_ref(&request);
+
+        assert_eq!(notification, Notification::from(request.clone()));
+        assert_eq!(request.id, Some(Id::from(1)));
+        assert_eq!(notification.with_id(1), request);
+    }
+
+    #[test]
+    fn test_notification_borrowed_method() {
+        let json = r#"{"jsonrpc":"2.0","method":"update","params":[1,2]}"#;
+
+        let notification = Notification::<Vec<i32>>::deserialize_borrowed(
+            &mut serde_json::Deserializer::from_str(json),
+        )
+        .unwrap();
+        assert!(matches!(notification.method, Cow::Borrowed("update")));
+        assert!(matches!(
+            notification.into_owned().method,
+            Cow::Owned(method) if method == "update"
+        ));
+    }
+
+    #[test]
+    fn test_notification_try_from_value() {
+        let value = serde_json::json!({"jsonrpc": "2.0", "method": "m", "params": [0, 1]});
+
+        assert_eq!(
+            Notification::try_from(value).unwrap(),
+            Notification::new("m", vec![0, 1])
+        );
+
+        let value = serde_json::json!({"jsonrpc": "2.0", "params": [0, 1]});
+
+        assert!(matches!(
+            Notification::<Vec<i32>>::try_from(value),
+            Err(Error::Json(_))
+        ));
+    }
 }
@@ -0,0 +1,60 @@
+use core::fmt;
+
+use serde::{de, Deserialize, Serialize};
+
+/// The JSON Null value, e.g. the **result** of a method returning nothing.
+///
+/// Serializes as Null like `()`, but reads clearer in signatures such as
+/// `Response<Null, E>`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy, Default)]
+pub struct Null;
+
+impl Serialize for Null {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_unit()
+    }
+}
+
+impl<'de> Deserialize<'de> for Null {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct NullVisitor;
+
+        impl<'de> de::Visitor<'de> for NullVisitor {
+            type Value = Null;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("null")
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(Null)
+            }
+        }
+
+        deserializer.deserialize_unit(NullVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{test_utils::snapshot, Id, Response, ResponseContent};
+
+    #[test]
+    fn test_null_serde() {
+        snapshot!(Response::new(
+            ResponseContent::<_, ()>::Result(Null),
+            Some(Id::from(1))
+        ));
+
+        assert!(serde_json::from_str::<Null>("0").is_err());
+        assert!(serde_json::from_str::<Null>("{}").is_err());
+    }
+}
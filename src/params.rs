@@ -0,0 +1,254 @@
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt;
+
+use serde::{de, Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The **params** of a method taking no parameters.
+///
+/// Causes the **params** member to be omitted, instead of being serialized
+/// as Null like `()`. Deserializes from an omitted or Null **params**.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub struct NoParams;
+
+impl Serialize for NoParams {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_none()
+    }
+}
+
+impl<'de> Deserialize<'de> for NoParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct NoParamsVisitor;
+
+        impl<'de> de::Visitor<'de> for NoParamsVisitor {
+            type Value = NoParams;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("no params")
+            }
+
+            fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(NoParams)
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(NoParams)
+            }
+        }
+
+        deserializer.deserialize_option(NoParamsVisitor)
+    }
+}
+
+/// Dynamically typed **params**, by-position or by-name.
+///
+/// If present, parameters for the rpc call MUST be provided as a Structured
+/// value. Either by-position through an Array or by-name through an Object.
+///
+/// - by-position: params MUST be an Array, containing the values in the
+///   Server expected order.
+/// - by-name: params MUST be an Object, with member names that match the
+///   Server expected parameter names. The absence of expected names MAY result
+///   in an error being generated. The names MUST match exactly, including
+///   case, to the method's expected parameters.
+///
+/// [`Params::None`] omits the **params** member.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub enum Params {
+    Positional(Vec<Value>),
+    Named(Map<String, Value>),
+    #[default]
+    None,
+}
+
+impl Params {
+    /// The by-position parameter at `index`.
+    pub fn get_positional(&self, index: usize) -> Option<&Value> {
+        match self {
+            Params::Positional(values) => values.get(index),
+            _ => None,
+        }
+    }
+
+    /// The by-name parameter `key`.
+    pub fn get_named(&self, key: &str) -> Option<&Value> {
+        match self {
+            Params::Named(values) => values.get(key),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for Params {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Params::Positional(values) => values.serialize(serializer),
+            Params::Named(values) => values.serialize(serializer),
+            Params::None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Params {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ParamsVisitor;
+
+        impl<'de> de::Visitor<'de> for ParamsVisitor {
+            type Value = Params;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an Array or an Object")
+            }
+
+            fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(Params::None)
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(Params::None)
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                match Value::deserialize(deserializer)? {
+                    Value::Array(values) => Ok(Params::Positional(values)),
+                    Value::Object(values) => Ok(Params::Named(values)),
+                    Value::Null => Ok(Params::None),
+                    _ => Err(de::Error::invalid_type(
+                        de::Unexpected::Other("a Primitive"),
+                        &self,
+                    )),
+                }
+            }
+        }
+
+        deserializer.deserialize_option(ParamsVisitor)
+    }
+}
+
+/// [`Params`] which also accept a Primitive value, as sent by some lax
+/// peers, wrapping it as a single by-position parameter.
+///
+/// Serializes the same as its [`Params`].
+#[derive(Serialize, PartialEq, Eq, Debug, Clone, Default)]
+#[serde(transparent)]
+pub struct LenientParams(pub Params);
+
+impl<'de> Deserialize<'de> for LenientParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let params = match Option::<Value>::deserialize(deserializer)? {
+            None | Some(Value::Null) => Params::None,
+            Some(Value::Array(values)) => Params::Positional(values),
+            Some(Value::Object(values)) => Params::Named(values),
+            Some(value) => Params::Positional(vec![value]),
+        };
+
+        Ok(Self(params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{test_utils::snapshot, Id, Notification, Request};
+
+    #[test]
+    fn test_no_params_serde() {
+        snapshot!(Request::new("method", NoParams, Some(Id::from(1))));
+        snapshot!(Notification::new("method", NoParams));
+    }
+
+    #[test]
+    fn test_params_shapes() {
+        let request: Request<Params> =
+            serde_json::from_str(r#"{"jsonrpc":"2.0","method":"m","params":[1,"a"],"id":1}"#)
+                .unwrap();
+        assert_eq!(request.params.get_positional(1), Some(&Value::from("a")));
+        assert_eq!(request.params.get_named("a"), None);
+
+        let request: Request<Params> =
+            serde_json::from_str(r#"{"jsonrpc":"2.0","method":"m","params":{"a":1},"id":1}"#)
+                .unwrap();
+        assert_eq!(request.params.get_named("a"), Some(&Value::from(1)));
+        assert_eq!(request.params.get_positional(0), None);
+
+        for json in [
+            r#"{"jsonrpc":"2.0","method":"m","id":1}"#,
+            r#"{"jsonrpc":"2.0","method":"m","params":null,"id":1}"#,
+        ] {
+            let request: Request<Params> = serde_json::from_str(json).unwrap();
+            assert_eq!(request.params, Params::None);
+        }
+
+        assert!(serde_json::from_str::<Request<Params>>(
+            r#"{"jsonrpc":"2.0","method":"m","params":1,"id":1}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_lenient_params() {
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":5,"id":1}"#;
+
+        let request: Request<LenientParams> = serde_json::from_str(json).unwrap();
+        assert_eq!(request.params.0, Params::Positional(vec![5.into()]));
+        assert!(serde_json::from_str::<Request<Params>>(json).is_err());
+
+        for (json, params) in [
+            (
+                r#"{"jsonrpc":"2.0","method":"m","params":[5],"id":1}"#,
+                Params::Positional(vec![5.into()]),
+            ),
+            (
+                r#"{"jsonrpc":"2.0","method":"m","params":{"a":5},"id":1}"#,
+                Params::Named(Map::from_iter([("a".to_string(), 5.into())])),
+            ),
+            (r#"{"jsonrpc":"2.0","method":"m","id":1}"#, Params::None),
+        ] {
+            let request: Request<LenientParams> = serde_json::from_str(json).unwrap();
+            assert_eq!(request.params.0, params);
+            assert_eq!(serde_json::to_string(&request).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn test_params_serde() {
+        snapshot!(Request::new(
+            "method",
+            Params::Positional(vec![1.into(), "a".into()]),
+            Some(Id::from(1))
+        ));
+        snapshot!(Notification::new("method", Params::None));
+    }
+
+    #[test]
+    fn test_no_params_null() {
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":null,"id":1}"#;
+        let request: Request<NoParams> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(request, Request::new("m", NoParams, Some(Id::from(1))));
+        assert!(serde_json::from_str::<Request<NoParams>>(
+            r#"{"jsonrpc":"2.0","method":"m","params":[],"id":1}"#
+        )
+        .is_err());
+    }
+}
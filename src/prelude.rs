@@ -0,0 +1,19 @@
+//! The most commonly used types and traits.
+//!
+//! ```
+//! use jsonrpc_types::prelude::*;
+//!
+//! let request = Request::new("subtract", [42, 23], Some(Id::from(1)));
+//!
+//! assert_eq!(
+//!     request.to_json_string().unwrap(),
+//!     r#"{"jsonrpc":"2.0","method":"subtract","params":[42,23],"id":1}"#
+//! );
+//! ```
+
+#[cfg(feature = "std")]
+pub use crate::IntoResponseError;
+pub use crate::{
+    Batch, Error, ErrorCode, Id, Message, MethodCall, Notification, Params, Request, Response,
+    ResponseContent, ResponseError,
+};
@@ -0,0 +1,204 @@
+use std::{
+    io::{self, Read},
+    marker::PhantomData,
+};
+
+use serde::de::{self, DeserializeOwned};
+
+use crate::Error;
+
+/// Reads the elements of a batch one at a time, without loading the whole
+/// Array in memory.
+///
+/// Only a single element is buffered at a time.
+///
+/// Iteration stops after the first error. Like [`Batch`](crate::Batch), an
+/// empty Array yields [`Error::EmptyBatch`].
+///
+/// Only whitespace may follow the closing `]`, which is checked after the
+/// last element is yielded, by reading the rest of the input. The call to
+/// `next` after the last element therefore blocks until the reader reaches
+/// EOF: on a connection that stays open, or carries more than one batch, give
+/// the `BatchReader` a reader limited to a single batch, such as an
+/// [`io::Read::take`] of its length.
+pub struct BatchReader<R, T> {
+    bytes: io::Bytes<io::BufReader<R>>,
+    state: State,
+    buf: Vec<u8>,
+    items: PhantomData<fn() -> T>,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum State {
+    Start,
+    Items,
+    /// After the closing `]`, with the rest of the input still unchecked.
+    End,
+    Done,
+}
+
+impl<R: Read, T> BatchReader<R, T> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            bytes: io::BufReader::new(reader).bytes(),
+            state: State::Start,
+            buf: Vec::new(),
+            items: PhantomData,
+        }
+    }
+
+    fn next_byte(&mut self) -> Result<Option<u8>, Error> {
+        self.bytes.next().transpose().map_err(Error::from)
+    }
+
+    /// Buffer the next element, returning whether it was the last one.
+    fn read_element(&mut self) -> Result<bool, Error> {
+        self.buf.clear();
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+
+        loop {
+            let byte = self
+                .next_byte()?
+                .ok_or_else(|| syntax("EOF while parsing a batch"))?;
+
+            if in_string {
+                match byte {
+                    _ if escaped => escaped = false,
+                    b'\\' => escaped = true,
+                    b'"' => in_string = false,
+                    _ => {}
+                }
+            } else {
+                match byte {
+                    b',' if depth == 0 => return Ok(false),
+                    b']' if depth == 0 => return Ok(true),
+                    b'"' => in_string = true,
+                    b'[' | b'{' => depth += 1,
+                    b']' | b'}' => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+
+            self.buf.push(byte);
+        }
+    }
+
+    /// Check that only whitespace follows the closing `]`.
+    fn read_end(&mut self) -> Result<(), Error> {
+        while let Some(byte) = self.next_byte()? {
+            if !byte.is_ascii_whitespace() {
+                return Err(syntax("trailing characters after a batch"));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_item(&mut self) -> Result<Option<T>, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let first = match self.state {
+            State::Start => {
+                loop {
+                    match self.next_byte()? {
+                        Some(b'[') => break,
+                        Some(byte) if byte.is_ascii_whitespace() => {}
+                        _ => return Err(syntax("expected `[` at the start of a batch")),
+                    }
+                }
+                true
+            }
+            State::Items => false,
+            State::End => {
+                self.state = State::Done;
+                return self.read_end().map(|()| None);
+            }
+            State::Done => return Ok(None),
+        };
+
+        let last = self.read_element()?;
+        self.state = if last { State::End } else { State::Items };
+
+        if self.buf.iter().all(u8::is_ascii_whitespace) {
+            return Err(if first && last {
+                Error::EmptyBatch
+            } else {
+                syntax("expected a value in a batch")
+            });
+        }
+
+        Ok(Some(serde_json::from_slice(&self.buf)?))
+    }
+}
+
+impl<R: Read, T: DeserializeOwned> Iterator for BatchReader<R, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.read_item().transpose();
+        if matches!(item, Some(Err(_))) {
+            self.state = State::Done;
+        }
+
+        item
+    }
+}
+
+fn syntax(message: &str) -> Error {
+    Error::Json(de::Error::custom(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Id, Request};
+
+    #[test]
+    fn test_batch_reader() {
+        let json = br#" [
+            {"jsonrpc":"2.0","method":"a","params":[1, [2]],"id":1},
+            {"jsonrpc":"2.0","method":"b]","params":["\"],"],"id":2} ,
+            {"jsonrpc":"2.0","method":"c","params":[],"id":3}
+        ] "#;
+
+        let requests = BatchReader::<_, Request<Vec<serde_json::Value>>>::new(&json[..])
+            .map(|r| r.map(|r| (r.method.into_owned(), r.id)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            requests,
+            [
+                ("a".to_string(), Some(Id::from(1))),
+                ("b]".to_string(), Some(Id::from(2))),
+                ("c".to_string(), Some(Id::from(3))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_reader_invalid() {
+        let mut reader = BatchReader::<_, i32>::new(&b"[]"[..]);
+        assert!(matches!(reader.next(), Some(Err(Error::EmptyBatch))));
+        assert!(reader.next().is_none());
+
+        let mut reader = BatchReader::<_, i32>::new(&b"[1, \"2\", 3]"[..]);
+        assert_eq!(reader.next().unwrap().unwrap(), 1);
+        assert!(matches!(reader.next(), Some(Err(Error::Json(_)))));
+        assert!(reader.next().is_none());
+
+        let mut reader = BatchReader::<_, i32>::new(&b"[1] x"[..]);
+        assert_eq!(reader.next().unwrap().unwrap(), 1);
+        assert!(matches!(reader.next(), Some(Err(Error::Json(_)))));
+        assert!(reader.next().is_none());
+
+        for json in ["1", "[1,]", "[1, 2", "[1]]", "[1][2]", "[1] ,"] {
+            let reader = BatchReader::<_, i32>::new(json.as_bytes());
+            assert!(reader.collect::<Result<Vec<_>, _>>().is_err(), "{json}");
+        }
+    }
+}
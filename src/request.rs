@@ -1,6 +1,14 @@
-use serde::{ser::SerializeStruct, Deserialize, Serialize};
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
 
-use crate::JSONRPC_V2;
+use serde::{de::DeserializeOwned, ser::SerializeStruct, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    de::{self, Absent, CowStr},
+    is_reserved_method, ser, Error, Id, IdFirst, Method, Response, ResponseContent, ResponseError,
+    Version,
+};
 
 /// Represents an rpc call to a Server.
 ///
@@ -26,50 +34,921 @@ use crate::JSONRPC_V2;
 /// [^1]: The use of Null as a value for the id member in a Request object is discouraged, because this specification uses a value of Null for Responses with an unknown id. Also, because JSON-RPC 1.0 uses an id value of Null for Notifications this could cause confusion in handling.
 ///
 /// [^2]: Fractional parts may be problematic, since many decimal fractions cannot be represented exactly as binary fractions.
-#[derive(Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
-pub struct Request<P> {
-    pub method: String,
+///
+/// The **id** type `I` defaults to [`Id`], but any type serializing as a String or Number can be used.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Request<'a, P, I = Id> {
+    /// The **jsonrpc** member, [`Version::V1`] omits it.
+    pub jsonrpc: Version,
+    pub method: Cow<'a, str>,
+    /// **params** is omitted if it serializes as `None`, so `P = Option<Q>`
+    /// sends absent **params** as `None` and Null ones as `Some(())`. Both
+    /// are deserialized as `None`.
+    ///
+    /// An absent **params** member is deserialized like a Null one, which
+    /// `()`, `Option`, `Value`, [`Params`](crate::Params) and
+    /// [`NoParams`](crate::NoParams) accept. Params requiring a value (e.g.
+    /// a `Vec` or a struct) fail with a missing field error.
+    ///
+    /// `P` is (de)serialized as is, so its own serde attributes (e.g.
+    /// `rename_all`) apply to its members, while the members of the Request
+    /// itself keep their names.
     pub params: P,
-    pub id: Option<u64>,
+    /// [`None`] omits the **id** member, which is *not* the same as a Null id.
+    ///
+    /// A Null id is deserialized as an `I` (e.g. `Id::Other(Value::Null)`),
+    /// so that the Request isn't mistaken for a Notification. Id types
+    /// without a Null value reject it.
+    pub id: Option<I>,
 }
 
-impl<P> Request<P> {
-    pub fn new(method: impl Into<String>, params: P, id: Option<u64>) -> Self {
+/// A [`Request`] owning its **method**.
+pub type OwnedRequest<P, I = Id> = Request<'static, P, I>;
+
+impl<'a, P, I> Request<'a, P, I> {
+    pub fn new(method: impl Into<Cow<'a, str>>, params: P, id: Option<I>) -> Self {
         Self {
+            jsonrpc: Version::V2,
             method: method.into(),
             params,
             id,
         }
     }
+
+    /// Replace the **id**, e.g. `Request::new("m", params, None).with_id(1)`.
+    ///
+    /// [`Notification::with_id`](crate::Notification::with_id) does the same
+    /// starting from a Notification.
+    pub fn with_id(self, id: impl Into<I>) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..self
+        }
+    }
+
+    /// Like [`Request::new`], but fails if the **method** name is reserved
+    /// (see [`is_reserved_method`]).
+    ///
+    /// Use [`Request::new`] to invoke a reserved method on purpose.
+    pub fn try_new(
+        method: impl Into<Cow<'a, str>>,
+        params: P,
+        id: Option<I>,
+    ) -> Result<Self, Error> {
+        let method = method.into();
+        if is_reserved_method(&method) {
+            return Err(Error::ReservedMethod {
+                method: method.into_owned(),
+            });
+        }
+
+        Ok(Self::new(method, params, id))
+    }
+
+    /// Deserialize a Request, borrowing the **method** from the input when
+    /// possible.
+    ///
+    /// The [`Deserialize`] implementation always allocates the **method**, so
+    /// that [`OwnedRequest`] is [`DeserializeOwned`](serde::de::DeserializeOwned).
+    pub fn deserialize_borrowed<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'a>,
+        P: Deserialize<'a>,
+        I: Deserialize<'a>,
+    {
+        if !deserializer.is_human_readable() {
+            let BinaryRequest {
+                method: CowStr(method),
+                params,
+                id,
+            } = BinaryRequest::deserialize(deserializer)?;
+            return Ok(Self::new(method, params, id));
+        }
+
+        let RawRequest {
+            jsonrpc,
+            method: CowStr(method),
+            params,
+            id,
+        } = RawRequest::deserialize(deserializer)?;
+
+        Ok(Self {
+            jsonrpc: match jsonrpc {
+                Some(jsonrpc) => jsonrpc,
+                None => de::missing_version()?,
+            },
+            method,
+            params: params.into_present()?,
+            id,
+        })
+    }
+
+    /// Replace the **params**, keeping the other members, e.g. to forward a
+    /// Request with rewritten raw params.
+    pub fn with_params<P2>(self, params: P2) -> Request<'a, P2, I> {
+        Request {
+            jsonrpc: self.jsonrpc,
+            method: self.method,
+            params,
+            id: self.id,
+        }
+    }
+
+    /// A read-only view of the Request.
+    pub fn as_ref(&self) -> RequestRef<'_, P, I> {
+        RequestRef {
+            method: &self.method,
+            params: &self.params,
+            id: self.id.as_ref(),
+        }
+    }
+
+    /// Whether both Requests invoke the same **method** with the same
+    /// **params**, regardless of their **id**.
+    pub fn same_call<J>(&self, other: &Request<'_, P, J>) -> bool
+    where
+        P: PartialEq,
+    {
+        self.method == other.method && self.params == other.params
+    }
+
+    pub fn method_is(&self, name: &str) -> bool {
+        self.method == name
+    }
+
+    /// The index of the first of `names` equal to the **method**.
+    pub fn matches_any<'n>(&self, names: impl IntoIterator<Item = &'n str>) -> Option<usize> {
+        names.into_iter().position(|name| self.method_is(name))
+    }
+
+    pub fn into_owned(self) -> OwnedRequest<P, I> {
+        Request {
+            jsonrpc: self.jsonrpc,
+            method: Cow::Owned(self.method.into_owned()),
+            params: self.params,
+            id: self.id,
+        }
+    }
 }
 
-impl<P: Serialize> Serialize for Request<P> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+impl<P, I: Clone> Request<'_, P, I> {
+    /// Reply to the Request with a **result**, copying its **id**.
+    pub fn success_response<R, E>(&self, result: R) -> Response<R, E, I> {
+        self.response(ResponseContent::Result(result))
+    }
+
+    /// Reply to the Request with an **error**, copying its **id**.
+    pub fn error_response<R, E>(&self, error: ResponseError<E>) -> Response<R, E, I> {
+        self.response(ResponseContent::Error(error))
+    }
+
+    /// Call the handler registered for the **method**, replying with its
+    /// outcome, or with a **Method not found** error if there is none.
+    ///
+    /// ```
+    /// use jsonrpc_types::{Id, Request, ResponseError};
+    ///
+    /// let add = |p: &Vec<i32>| Ok::<_, ResponseError<()>>(p.iter().sum());
+    /// let request = Request::new("add", vec![1, 2], Some(Id::from(1)));
+    ///
+    /// let response = request.dispatch(&[("add", &add)]);
+    /// assert_eq!(response.result(), Some(&3));
+    /// ```
+    pub fn dispatch<R, E>(&self, handlers: &[(&str, Handler<'_, P, R, E>)]) -> Response<R, E, I> {
+        let content = match self.matches_any(handlers.iter().map(|(name, _)| *name)) {
+            Some(index) => match (handlers[index].1)(&self.params) {
+                Ok(result) => ResponseContent::Result(result),
+                Err(error) => ResponseContent::Error(error),
+            },
+            None => ResponseContent::Error(ResponseError::method_not_found()),
+        };
+
+        self.response(content)
+    }
+
+    fn response<R, E>(&self, content: ResponseContent<R, E>) -> Response<R, E, I> {
+        Response {
+            jsonrpc: self.jsonrpc,
+            ..Response::new(content, self.id.clone())
+        }
+    }
+}
+
+impl<P, I> Request<'static, P, I> {
+    /// Create a Request invoking `M`.
+    pub fn for_method<M: Method<Params = P>>(params: P, id: Option<I>) -> Self {
+        Self::new(M::NAME, params, id)
+    }
+}
+
+impl<'a> Request<'a, ()> {
+    /// Build a Request, starting with no **params** and no **id**.
+    pub fn builder(method: impl Into<Cow<'a, str>>) -> RequestBuilder<'a, ()> {
+        RequestBuilder {
+            method: method.into(),
+            params: (),
+            id: None,
+        }
+    }
+}
+
+/// A borrowed view of a [`Request`], see [`Request::as_ref`].
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct RequestRef<'a, P, I = Id> {
+    pub method: &'a str,
+    pub params: &'a P,
+    pub id: Option<&'a I>,
+}
+
+impl<P, I> Clone for RequestRef<'_, P, I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P, I> Copy for RequestRef<'_, P, I> {}
+
+/// A handler of a method, see [`Request::dispatch`].
+pub type Handler<'h, P, R, E = ()> = &'h dyn Fn(&P) -> Result<R, ResponseError<E>>;
+
+/// Builds a [`Request`], see [`Request::builder`].
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct RequestBuilder<'a, P, I = Id> {
+    method: Cow<'a, str>,
+    params: P,
+    id: Option<I>,
+}
+
+impl<'a, P, I> RequestBuilder<'a, P, I> {
+    pub fn params<Q>(self, params: Q) -> RequestBuilder<'a, Q, I> {
+        RequestBuilder {
+            method: self.method,
+            params,
+            id: self.id,
+        }
+    }
+
+    pub fn id(self, id: impl Into<I>) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> Request<'a, P, I> {
+        Request::new(self.method, self.params, self.id)
+    }
+}
+
+impl<P: Serialize, I: Serialize> Request<'_, P, I> {
+    fn serialize_ordered<S>(&self, serializer: S, id_first: bool) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
+        if !serializer.is_human_readable() {
+            // binary formats are positional, so the same fields are always
+            // written, except for the version which is meaningless to them
+            let mut state = serializer.serialize_struct("Request", 3)?;
+            state.serialize_field("method", &self.method)?;
+            state.serialize_field("params", &self.params)?;
+            state.serialize_field("id", &self.id)?;
+            return state.end();
+        }
+
         let mut state = serializer.serialize_struct("Request", 4)?;
-        state.serialize_field("jsonrpc", JSONRPC_V2)?;
+        if id_first {
+            serialize_id(&mut state, &self.id)?;
+        }
+        if ser::is_none(&self.jsonrpc) {
+            state.skip_field("jsonrpc")?;
+        } else {
+            state.serialize_field("jsonrpc", &self.jsonrpc)?;
+        }
         state.serialize_field("method", &self.method)?;
-        state.serialize_field("params", &self.params)?;
-        state.serialize_field("id", &self.id)?;
+        if ser::is_none(&self.params) {
+            state.skip_field("params")?;
+        } else {
+            state.serialize_field("params", &self.params)?;
+        }
+        if !id_first {
+            serialize_id(&mut state, &self.id)?;
+        }
         state.end()
     }
 }
 
+fn serialize_id<S: SerializeStruct, I: Serialize>(
+    state: &mut S,
+    id: &Option<I>,
+) -> Result<(), S::Error> {
+    match id {
+        Some(id) => state.serialize_field("id", id),
+        None => state.skip_field("id"),
+    }
+}
+
+/// A concise summary for logging, e.g. `request "subtract" (id=1)`.
+impl<P, I: fmt::Display> fmt::Display for Request<'_, P, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "request {:?}", self.method)?;
+        match &self.id {
+            Some(id) => write!(f, " (id={id})"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<P: Serialize, I: Serialize> Serialize for Request<'_, P, I> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.serialize_ordered(serializer, false)
+    }
+}
+
+impl<P: Serialize, I: Serialize> Serialize for IdFirst<&Request<'_, P, I>> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize_ordered(serializer, true)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(
+    rename = "Request",
+    bound = "M: Deserialize<'de>, P: Deserialize<'de>, I: Deserialize<'de>"
+)]
+struct RawRequest<M, P, I> {
+    #[serde(default, deserialize_with = "de::some")]
+    jsonrpc: Option<Version>,
+    method: M,
+    #[serde(default = "Absent::default")]
+    params: Absent<P>,
+    #[serde(default = "Option::default", deserialize_with = "de::some")]
+    id: Option<I>,
+}
+
+/// A Request in a binary format, see [`Version`].
+#[derive(Deserialize)]
+#[serde(rename = "Request")]
+struct BinaryRequest<M, P, I> {
+    method: M,
+    params: P,
+    id: Option<I>,
+}
+
+impl<'de, P: Deserialize<'de>, I: Deserialize<'de>> Deserialize<'de> for Request<'_, P, I> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            let BinaryRequest { method, params, id } =
+                BinaryRequest::<String, _, _>::deserialize(deserializer)?;
+            return Ok(Self::new(method, params, id));
+        }
+
+        let RawRequest {
+            jsonrpc,
+            method,
+            params,
+            id,
+        } = RawRequest::<String, _, _>::deserialize(deserializer)?;
+
+        Ok(Self {
+            jsonrpc: match jsonrpc {
+                Some(jsonrpc) => jsonrpc,
+                None => de::missing_version()?,
+            },
+            method: Cow::Owned(method),
+            params: params.into_present()?,
+            id,
+        })
+    }
+}
+
+impl<P: DeserializeOwned, I: DeserializeOwned> TryFrom<Value> for Request<'_, P, I> {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+impl<P, I> Request<'_, P, I> {
+    /// Parse a Request from a JSON string.
+    ///
+    /// ```
+    /// use jsonrpc_types::Request;
+    ///
+    /// let json = r#"{"jsonrpc":"2.0","method":"subtract","params":[42,23],"id":1}"#;
+    /// let request = Request::<Vec<i32>>::from_json_str(json)?;
+    ///
+    /// assert_eq!(request.method, "subtract");
+    /// assert_eq!(request.to_json_string()?, json);
+    /// # Ok::<(), jsonrpc_types::Error>(())
+    /// ```
+    pub fn from_json_str(s: &str) -> Result<Self, Error>
+    where
+        P: DeserializeOwned,
+        I: DeserializeOwned,
+    {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// Like [`Request::from_json_str`], but fails on members other than
+    /// **jsonrpc**, **method**, **params** and **id**.
+    pub fn from_json_str_strict(s: &str) -> Result<Self, Error>
+    where
+        P: DeserializeOwned,
+        I: DeserializeOwned,
+    {
+        de::from_str_strict(s, &["jsonrpc", "method", "params", "id"])
+    }
+
+    /// Serialize the Request as a JSON string.
+    pub fn to_json_string(&self) -> Result<String, Error>
+    where
+        P: Serialize,
+        I: Serialize,
+    {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::test_utils::{snapshot, Params};
+    use crate::{
+        test_utils::{
+            positional::{self, Token},
+            snapshot, NestedParams, Params,
+        },
+        Message, NoParams, Notification,
+    };
 
     #[test]
     fn test_request_serde() {
-        snapshot!(Request::new("method", (), None));
-        snapshot!(Request::new("method", (), Some(1)));
-        snapshot!(Request::new("method", vec![0, 1], None));
-        snapshot!(Request::new("method", vec![0, 1], Some(1)));
-        snapshot!(Request::new("method", Params { p0: 0, p1: 1 }, None));
-        snapshot!(Request::new("method", Params { p0: 0, p1: 1 }, Some(1)));
+        snapshot!(Request::new("method", (), None::<Id>));
+        snapshot!(Request::new("method", (), Some(Id::from(1))));
+        snapshot!(Request::new("method", vec![0, 1], None::<Id>));
+        snapshot!(Request::new("method", vec![0, 1], Some(Id::from(1))));
+        snapshot!(Request::new("method", Params { p0: 0, p1: 1 }, None::<Id>));
+        snapshot!(Request::new(
+            "method",
+            Params { p0: 0, p1: 1 },
+            Some(Id::from(1))
+        ));
+        snapshot!(Request::new("method", (), Some(Id::from("abc-123"))));
+    }
+
+    #[test]
+    fn test_request_nested_params() {
+        snapshot!(Request::new(
+            "method",
+            NestedParams::sample(),
+            Some(Id::from(1))
+        ));
+        snapshot!(Request::new(
+            "method",
+            vec![NestedParams::sample()],
+            Some(Id::from(1))
+        ));
+    }
+
+    #[test]
+    fn test_request_display() {
+        assert_eq!(
+            Request::new("subtract", [42, 23], Some(Id::from(1))).to_string(),
+            r#"request "subtract" (id=1)"#
+        );
+        assert_eq!(
+            Request::new("subtract", (), Some(Id::from("abc"))).to_string(),
+            r#"request "subtract" (id="abc")"#
+        );
+        assert_eq!(
+            Request::new("subtract", (), None::<Id>).to_string(),
+            r#"request "subtract""#
+        );
+    }
+
+    #[test]
+    fn test_request_with_id() {
+        let request = Request::new("m", [1], Some(Id::from(1)));
+
+        assert_eq!(Request::new("m", [1], None).with_id(1), request);
+        assert_eq!(Notification::new("m", [1]).with_id(1), request);
+        assert_eq!(request.clone().with_id("a").id, Some(Id::from("a")));
+    }
+
+    #[test]
+    fn test_request_negative_id() {
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":null,"id":-5}"#;
+        let request: Request<()> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(request, Request::new("m", (), Some((-5).into())));
+        assert_eq!(serde_json::to_string(&request).unwrap(), json);
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(request.clone());
+        assert!(set.contains(&request));
+    }
+
+    #[test]
+    fn test_request_absent_id() {
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":null}"#;
+        let request: Request<()> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(request.id, None);
+        assert_eq!(serde_json::to_string(&request).unwrap(), json);
+    }
+
+    #[test]
+    fn test_request_null_id() {
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":null,"id":null}"#;
+
+        let request: Request<()> = serde_json::from_str(json).unwrap();
+        assert_eq!(request.id, Some(Id::Other(Value::Null)));
+        assert_eq!(serde_json::to_string(&request).unwrap(), json);
+
+        let message: Message<(), (), ()> = serde_json::from_str(json).unwrap();
+        assert_eq!(message, Message::Request(request));
+
+        assert!(serde_json::from_str::<Request<(), i64>>(json).is_err());
+    }
+
+    #[test]
+    fn test_request_absent_params() {
+        snapshot!(Request::new("method", None::<()>, Some(Id::from(1))));
+        snapshot!(Request::new("method", Some(vec![0, 1]), Some(Id::from(1))));
+        // serde reads a Null back as `None`, so this one doesn't round trip
+        insta::assert_json_snapshot!(Request::new("method", Some(()), Some(Id::from(1))));
+
+        let json = r#"{"jsonrpc":"2.0","method":"m","id":1}"#;
+        let request: Request<Option<Params>> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(request.params, None);
+        assert_eq!(serde_json::to_string(&request).unwrap(), json);
+    }
+
+    #[test]
+    fn test_request_binary_format() {
+        for request in [
+            Request::new("m", vec![1], Some(Id::from(1))),
+            Request::new("m", vec![], Some(Id::from("1"))),
+            Request::new("m", vec![2, 3], None),
+        ] {
+            assert_eq!(positional::round_trip(&request).unwrap(), request);
+            assert_eq!(
+                positional::to_tokens(&IdFirst(&request)).unwrap(),
+                positional::to_tokens(&request).unwrap()
+            );
+        }
+
+        // neither the version nor the absence of params are written
+        assert_eq!(
+            positional::to_tokens(&Request::new("m", NoParams, Some(Id::from(1)))).unwrap(),
+            [
+                Token::Str("m".to_string()),
+                Token::None,
+                Token::Some,
+                Token::Variant(0),
+                Token::I64(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_request_unit_params() {
+        for json in [
+            r#"{"jsonrpc":"2.0","method":"m","id":1}"#,
+            r#"{"jsonrpc":"2.0","method":"m","params":null,"id":1}"#,
+        ] {
+            let request: Request<()> = serde_json::from_str(json).unwrap();
+            assert_eq!(request, Request::new("m", (), Some(Id::from(1))));
+
+            let request: Request<NoParams> = serde_json::from_str(json).unwrap();
+            assert_eq!(request.params, NoParams);
+        }
+
+        let json = r#"{"jsonrpc":"2.0","method":"m","id":1}"#;
+        let request: Request<Value> = serde_json::from_str(json).unwrap();
+        assert_eq!(request.params, Value::Null);
+
+        for result in [
+            serde_json::from_str::<Request<Params>>(json).map(drop),
+            serde_json::from_str::<Request<Vec<i32>>>(json).map(drop),
+        ] {
+            assert_eq!(result.unwrap_err().to_string(), "missing field `params`");
+        }
+    }
+
+    #[test]
+    fn test_request_invalid_version() {
+        for json in [
+            r#"{"jsonrpc":"1.0","method":"m","params":null,"id":1}"#,
+            r#"{"jsonrpc":2.0,"method":"m","params":null,"id":1}"#,
+        ] {
+            assert!(serde_json::from_str::<Request<()>>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn test_request_responses() {
+        let request = Request::new("m", (), Some(Id::from("abc")));
+
+        let response = request.success_response::<_, ()>(1);
+        assert_eq!(response.id, request.id);
+        assert_eq!(response.result(), Some(&1));
+
+        let response = request.error_response::<(), ()>(ResponseError::internal_error());
+        assert_eq!(response.id, request.id);
+        assert_eq!(response.error(), Some(&ResponseError::internal_error()));
+
+        let notification = Request::new("m", (), None::<Id>);
+        assert_eq!(notification.success_response::<_, ()>(1).id, None);
+        assert_eq!(
+            notification
+                .error_response::<(), ()>(ResponseError::internal_error())
+                .id,
+            None
+        );
+    }
+
+    #[test]
+    fn test_request_try_new() {
+        assert_eq!(
+            Request::try_new("m", (), Some(Id::from(1))).unwrap(),
+            Request::new("m", (), Some(Id::from(1)))
+        );
+        assert!(matches!(
+            Request::try_new("rpc.m", (), Some(Id::from(1))),
+            Err(Error::ReservedMethod { method }) if method == "rpc.m"
+        ));
+    }
+
+    #[test]
+    fn test_request_id_first() {
+        insta::assert_json_snapshot!(IdFirst(&Request::new(
+            "method",
+            Params { p0: 0, p1: 1 },
+            Some(Id::from(1))
+        )));
+        insta::assert_json_snapshot!(IdFirst(&Request::new("method", (), None::<Id>)));
+    }
+
+    #[test]
+    fn test_request_strict() {
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":[],"id":1,"extra":true}"#;
+
+        assert!(Request::<Vec<i32>>::from_json_str(json).is_ok());
+        assert!(matches!(
+            Request::<Vec<i32>>::from_json_str_strict(json),
+            Err(Error::UnknownMember { member }) if member == "extra"
+        ));
+        assert!(Request::<Vec<i32>>::from_json_str_strict(
+            r#"{"jsonrpc":"2.0","method":"m","params":[],"id":1}"#
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_request_as_ref() {
+        fn describe<P: std::fmt::Debug>(request: RequestRef<'_, P>) -> String {
+            format!("{}({:?}) #{:?}", request.method, request.params, request.id)
+        }
+
+        let request = Request::new("m", Params { p0: 0, p1: 1 }, Some(Id::from(1)));
+
+        assert_eq!(
+            describe(request.as_ref()),
+            "m(Params { p0: 0, p1: 1 }) #Some(Number(1))"
+        );
+        assert_eq!(request.as_ref().params, &request.params);
+        assert_eq!(Request::new("n", (), None::<Id>).as_ref().id, None);
+    }
+
+    #[test]
+    fn test_request_same_call() {
+        let request = Request::new("m", vec![0, 1], Some(Id::from(1)));
+
+        assert!(request.same_call(&Request::new("m", vec![0, 1], Some(Id::from("2")))));
+        assert!(request.same_call(&Request::new("m", vec![0, 1], None::<Id>)));
+        assert!(!request.same_call(&Request::new("m", vec![1, 0], Some(Id::from(1)))));
+        assert!(!request.same_call(&Request::new("n", vec![0, 1], Some(Id::from(1)))));
+        assert_ne!(request, Request::new("m", vec![0, 1], Some(Id::from(2))));
+    }
+
+    #[test]
+    fn test_request_method_is() {
+        let request = Request::new("b", (), Some(Id::from(1)));
+
+        assert!(request.method_is("b"));
+        assert!(!request.method_is("a"));
+        assert_eq!(request.matches_any(["a", "b", "b"]), Some(1));
+        assert_eq!(request.matches_any(["a", "c"]), None);
+    }
+
+    #[test]
+    fn test_request_dispatch() {
+        let add = |p: &Vec<i32>| Ok(p.iter().sum());
+        let fail = |_: &Vec<i32>| Err(ResponseError::<()>::invalid_params());
+        let handlers: &[(&str, Handler<Vec<i32>, i32>)] = &[("add", &add), ("fail", &fail)];
+
+        let response = Request::new("add", vec![1, 2], Some(Id::from(1))).dispatch(handlers);
+        assert_eq!(response.result(), Some(&3));
+        assert_eq!(response.id, Some(Id::from(1)));
+
+        let response = Request::new("fail", vec![], Some(Id::from(2))).dispatch(handlers);
+        assert_eq!(response.error(), Some(&ResponseError::invalid_params()));
+
+        let response = Request::new("sub", vec![], Some(Id::from(3))).dispatch(handlers);
+        assert_eq!(response.error(), Some(&ResponseError::method_not_found()));
+        assert_eq!(response.id, Some(Id::from(3)));
+    }
+
+    #[test]
+    fn test_request_renamed_params() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        #[serde(rename_all = "camelCase")]
+        struct CamelCase {
+            first_name: String,
+            is_admin: bool,
+        }
+
+        snapshot!(Request::new(
+            "getUser",
+            CamelCase {
+                first_name: "a".to_string(),
+                is_admin: true,
+            },
+            Some(Id::from(1))
+        ));
+    }
+
+    #[test]
+    fn test_request_builder() {
+        assert_eq!(
+            Request::builder("m").params(vec![0, 1]).id(1).build(),
+            Request::new("m", vec![0, 1], Some(1.into()))
+        );
+        assert_eq!(
+            Request::builder("m").id("abc").params(vec![0, 1]).build(),
+            Request::new("m", vec![0, 1], Some("abc".into()))
+        );
+        assert_eq!(
+            Request::builder("m").params(vec![0, 1]).build(),
+            Request::new("m", vec![0, 1], None)
+        );
+        assert_eq!(Request::builder("m").build(), Request::new("m", (), None));
+    }
+
+    #[test]
+    fn test_request_custom_id() {
+        #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+        struct Uuid(String);
+
+        let request = Request::new("m", vec![0, 1], Some(Uuid("7c9e6679".to_string())));
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":[0,1],"id":"7c9e6679"}"#;
+
+        assert_eq!(serde_json::to_string(&request).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<Request<Vec<i32>, Uuid>>(json).unwrap(),
+            request
+        );
+    }
+
+    #[test]
+    fn test_request_raw_params() {
+        use serde_json::value::RawValue;
+
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":{"p0":0,"p1":1},"id":1}"#;
+
+        let request: Request<Box<RawValue>> = serde_json::from_str(json).unwrap();
+        assert_eq!(request.method, "m");
+        assert_eq!(
+            serde_json::from_str::<Params>(request.params.get()).unwrap(),
+            Params { p0: 0, p1: 1 }
+        );
+        assert_eq!(serde_json::to_string(&request).unwrap(), json);
+
+        let request: Request<&RawValue> = serde_json::from_str(json).unwrap();
+        assert_eq!(request.params.get(), r#"{"p0":0,"p1":1}"#);
+        assert_eq!(serde_json::to_string(&request).unwrap(), json);
+    }
+
+    #[test]
+    fn test_request_forward_raw_params() {
+        use serde_json::value::RawValue;
+
+        let json = r#"{"jsonrpc":"2.0","method":"m","params":[1, {"a" : 2}],"id":1}"#;
+        let request: Request<Box<RawValue>> = serde_json::from_str(json).unwrap();
+
+        // forwarded verbatim, whitespace included
+        assert_eq!(request.params.get(), r#"[1, {"a" : 2}]"#);
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"jsonrpc":"2.0","method":"m","params":[1, {"a" : 2}],"id":1}"#
+        );
+
+        let params = RawValue::from_string("[3]".to_string()).unwrap();
+        let forwarded = request.with_params(params);
+        assert_eq!(
+            serde_json::to_string(&forwarded).unwrap(),
+            r#"{"jsonrpc":"2.0","method":"m","params":[3],"id":1}"#
+        );
+    }
+
+    #[test]
+    fn test_request_invalid_version_message() {
+        let json = r#"{"jsonrpc":2,"method":"m","params":null,"id":1}"#;
+        let error = serde_json::from_str::<Request<()>>(json).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with(r#"invalid jsonrpc version: expected "2.0", found 2"#));
+    }
+
+    #[test]
+    fn test_request_borrowed_method() {
+        let json = r#"{"jsonrpc":"2.0","method":"subtract","params":[42,23],"id":1}"#;
+
+        let request = Request::<Vec<i32>>::deserialize_borrowed(
+            &mut serde_json::Deserializer::from_str(json),
+        )
+        .unwrap();
+        assert!(matches!(request.method, Cow::Borrowed("subtract")));
+
+        let request: OwnedRequest<Vec<i32>> = serde_json::from_str(json).unwrap();
+        assert!(matches!(request.method, Cow::Owned(_)));
+
+        // escaped strings can't be borrowed
+        let json = r#"{"jsonrpc":"2.0","method":"sub\ttract","params":[42,23],"id":1}"#;
+        let request = Request::<Vec<i32>>::deserialize_borrowed(
+            &mut serde_json::Deserializer::from_str(json),
+        )
+        .unwrap();
+        assert_eq!(request.method, "sub\ttract");
+    }
+
+    #[test]
+    fn test_request_unicode() {
+        snapshot!(Request::new(
+            "ünïcode.method",
+            vec!["emoji 😀".to_string(), "𝄞 clef".to_string()],
+            Some(Id::from("ïd"))
+        ));
+
+        // surrogate pairs escaped as in JavaScript
+        let json = r#"{"jsonrpc":"2.0","method":"\u00fcn\u00efcode.method","params":["\ud83d\ude00"],"id":1}"#;
+        let request: Request<Vec<String>> = serde_json::from_str(json).unwrap();
+        assert_eq!(request.method, "ünïcode.method");
+        assert_eq!(request.params, ["😀"]);
+
+        // unescaped multi-byte characters are borrowed whole
+        let json = r#"{"jsonrpc":"2.0","method":"ünïcode.😀","params":[],"id":1}"#;
+        let request = Request::<Vec<i32>>::deserialize_borrowed(
+            &mut serde_json::Deserializer::from_str(json),
+        )
+        .unwrap();
+        assert!(matches!(request.method, Cow::Borrowed("ünïcode.😀")));
+        assert_eq!(request.to_json_string().unwrap(), json);
+    }
+
+    #[test]
+    fn test_request_for_method() {
+        struct Subtract;
+
+        impl Method for Subtract {
+            const NAME: &'static str = "subtract";
+            type Params = Params;
+            type Result = i64;
+        }
+
+        assert_eq!(
+            Request::for_method::<Subtract>(Params { p0: 42, p1: 23 }, Some(Id::from(1))),
+            Request::new("subtract", Params { p0: 42, p1: 23 }, Some(Id::from(1)))
+        );
+    }
+
+    #[test]
+    fn test_request_try_from_value() {
+        let value = serde_json::json!({"jsonrpc": "2.0", "method": "m", "params": [0, 1], "id": 1});
+
+        assert_eq!(
+            Request::try_from(value).unwrap(),
+            Request::new("m", vec![0, 1], Some(Id::from(1)))
+        );
+
+        let value = serde_json::json!({"jsonrpc": "1.0", "method": "m", "params": [0, 1], "id": 1});
+
+        assert!(matches!(
+            Request::<Vec<i32>>::try_from(value),
+            Err(Error::InvalidVersion { .. })
+        ));
     }
 }
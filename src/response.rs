@@ -1,6 +1,19 @@
-use serde::{ser::SerializeStruct, Deserialize, Serialize};
+use alloc::{borrow::Cow, string::String};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::RangeInclusive,
+};
 
-use crate::JSONRPC_V2;
+use serde::{
+    de::{self, DeserializeOwned},
+    ser::{SerializeMap, SerializeStruct},
+    Deserialize, Serialize,
+};
+use serde_json::{Map, Value};
+
+use crate::{ser, Error, ErrorCode, ErrorKind, Id, IdFirst, Version, SERVER_ERROR_RANGE};
 
 /// When a rpc call is made, the Server MUST reply with a Response, except for in the case of Notifications.
 ///
@@ -29,28 +42,272 @@ use crate::JSONRPC_V2;
 /// If there was an error in detecting the id in the Request object (e.g. Parse error/Invalid Request), it MUST be Null.
 ///
 /// Either the result member or error member MUST be included, but both members MUST NOT be included.
-#[derive(Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
-pub struct Response<R, E> {
+///
+/// The **id** type `I` defaults to [`Id`], see [`Request`](crate::Request).
+///
+/// The **error** data type `E` defaults to [`Value`], for when its concrete
+/// type doesn't matter:
+///
+/// ```
+/// use jsonrpc_types::{Response, ResponseError};
+///
+/// let response: Response<i32> = serde_json::from_str(
+///     r#"{"jsonrpc":"2.0","error":{"code":-32000,"message":"busy","data":[1]},"id":1}"#,
+/// )
+/// .unwrap();
+///
+/// let error: &ResponseError = response.error().unwrap();
+/// assert_eq!(error.data, Some(serde_json::json!([1])));
+/// ```
+///
+/// The **result** and **error** data may borrow from the input, e.g. to
+/// inspect them without parsing as `Response<&RawValue, &RawValue>`
+/// (with serde_json's `raw_value` feature).
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Response<R, E = Value, I = Id> {
+    /// The **jsonrpc** member, [`Version::V1`] omits it.
+    pub jsonrpc: Version,
     /// Contains the **result** or **error** contents.
-    #[serde(flatten)]
     pub content: ResponseContent<R, E>,
-    pub id: Option<u64>,
+    /// The **id** member is always present, [`None`] is serialized as Null.
+    pub id: Option<I>,
+}
+
+impl<R, E, I> Response<R, E, I> {
+    pub fn new(content: ResponseContent<R, E>, id: Option<I>) -> Self {
+        Self {
+            jsonrpc: Version::V2,
+            content,
+            id,
+        }
+    }
+
+    /// Reply with the outcome of a call, the inverse of
+    /// [`Response::into_result`].
+    pub fn from_result(result: Result<R, ResponseError<E>>, id: Option<I>) -> Self {
+        let content = match result {
+            Ok(result) => ResponseContent::Result(result),
+            Err(error) => ResponseContent::Error(error),
+        };
+
+        Self::new(content, id)
+    }
+
+    /// Replace the **id**.
+    pub fn with_id(self, id: impl Into<I>) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..self
+        }
+    }
+
+    /// Clear the **id**, e.g. when it couldn't be detected after all.
+    pub fn with_null_id(self) -> Self {
+        Self { id: None, ..self }
+    }
+
+    /// Check the **error** against the specification, which deserialization
+    /// and the constructors don't enforce.
+    ///
+    /// The **message** must not be empty, and the **code** must not be
+    /// within the range reserved for pre-defined errors (-32768 to -32000)
+    /// without being defined.
+    pub fn validate(&self) -> Result<(), Error> {
+        let Some(error) = self.error() else {
+            return Ok(());
+        };
+
+        if error.message.is_empty() {
+            return Err(Error::EmptyErrorMessage);
+        }
+        if RESERVED_ERROR_RANGE.contains(&error.code)
+            && ErrorCode::classify(error.code) == ErrorKind::Application
+        {
+            return Err(Error::ReservedErrorCode { code: error.code });
+        }
+
+        Ok(())
+    }
+
+    /// The **result** member, if the call succeeded.
+    pub fn result(&self) -> Option<&R> {
+        self.content.as_result()
+    }
+
+    /// The **error** member, if the call failed.
+    pub fn error(&self) -> Option<&ResponseError<E>> {
+        self.content.as_error()
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(self.content, ResponseContent::Error(_))
+    }
+
+    /// Convert the **result** or **error** into a [`Result`], discarding the id.
+    pub fn into_result(self) -> Result<R, ResponseError<E>> {
+        self.content.into_result()
+    }
+
+    /// Split into the **id** and the outcome of the call, e.g. to match the
+    /// Response to its Request.
+    pub fn into_parts(self) -> (Option<I>, Result<R, ResponseError<E>>) {
+        (self.id, self.content.into_result())
+    }
+
+    /// Map the **result** of a successful Response, leaving an error untouched.
+    pub fn map_result<R2, F>(self, f: F) -> Response<R2, E, I>
+    where
+        F: FnOnce(R) -> R2,
+    {
+        Response {
+            jsonrpc: self.jsonrpc,
+            content: self.content.map_result(f),
+            id: self.id,
+        }
+    }
+
+    /// Map the **error** of a failed Response, leaving a result untouched.
+    pub fn map_error<E2, F>(self, f: F) -> Response<R, E2, I>
+    where
+        F: FnOnce(ResponseError<E>) -> ResponseError<E2>,
+    {
+        Response {
+            jsonrpc: self.jsonrpc,
+            content: self.content.map_error(f),
+            id: self.id,
+        }
+    }
+
+    /// Like [`Response::map_error`], but `f` may fail, e.g. when reparsing the
+    /// **data** of an error.
+    pub fn try_map_error<E2, X, F>(self, f: F) -> Result<Response<R, E2, I>, X>
+    where
+        F: FnOnce(ResponseError<E>) -> Result<ResponseError<E2>, X>,
+    {
+        Ok(Response {
+            jsonrpc: self.jsonrpc,
+            content: self.content.try_map_error(f)?,
+            id: self.id,
+        })
+    }
 }
 
 impl<R, E> Response<R, E> {
-    pub fn new(content: ResponseContent<R, E>, id: Option<u64>) -> Self {
-        Self { content, id }
+    /// Build a successful Response, starting with a Null **id**.
+    pub fn success(result: R) -> ResponseBuilder<R, E> {
+        ResponseBuilder {
+            content: ResponseContent::Result(result),
+            id: None,
+        }
+    }
+
+    /// Build a failed Response, starting with a Null **id**.
+    pub fn failure(error: ResponseError<E>) -> ResponseBuilder<R, E> {
+        ResponseBuilder {
+            content: ResponseContent::Error(error),
+            id: None,
+        }
     }
 }
 
+/// Builds a [`Response`], see [`Response::success`] and [`Response::failure`].
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct ResponseBuilder<R, E = Value, I = Id> {
+    content: ResponseContent<R, E>,
+    id: Option<I>,
+}
+
+impl<R, E, I> ResponseBuilder<R, E, I> {
+    pub fn id(self, id: impl Into<I>) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> Response<R, E, I> {
+        Response::new(self.content, self.id)
+    }
+}
+
+/// An error Response with a Null **id**, e.g. for a Parse error where the id
+/// couldn't be detected.
+impl<R, E, I> From<ResponseError<E>> for Response<R, E, I> {
+    fn from(error: ResponseError<E>) -> Self {
+        Self::new(ResponseContent::Error(error), None)
+    }
+}
+
+/// Codes reserved for pre-defined errors.
+const RESERVED_ERROR_RANGE: RangeInclusive<i64> = -32768..=-32000;
+
 /// Contains either the **result** or **error** content of a [`Response`].
+///
+/// Serializes as an object with a single **result** or **error** member,
+/// named the same as in a [`Response`].
 #[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
-pub enum ResponseContent<R, E> {
+pub enum ResponseContent<R, E = Value> {
     Result(R),
     Error(ResponseError<E>),
 }
 
+impl<R, E> ResponseContent<R, E> {
+    /// The **result**, if the call succeeded.
+    pub fn as_result(&self) -> Option<&R> {
+        match self {
+            ResponseContent::Result(result) => Some(result),
+            ResponseContent::Error(_) => None,
+        }
+    }
+
+    /// The **error**, if the call failed.
+    pub fn as_error(&self) -> Option<&ResponseError<E>> {
+        match self {
+            ResponseContent::Result(_) => None,
+            ResponseContent::Error(error) => Some(error),
+        }
+    }
+
+    /// Convert into a [`Result`], so that an error can be propagated with `?`.
+    pub fn into_result(self) -> Result<R, ResponseError<E>> {
+        match self {
+            ResponseContent::Result(result) => Ok(result),
+            ResponseContent::Error(error) => Err(error),
+        }
+    }
+
+    pub fn map_result<R2, F>(self, f: F) -> ResponseContent<R2, E>
+    where
+        F: FnOnce(R) -> R2,
+    {
+        match self {
+            ResponseContent::Result(result) => ResponseContent::Result(f(result)),
+            ResponseContent::Error(error) => ResponseContent::Error(error),
+        }
+    }
+
+    pub fn map_error<E2, F>(self, f: F) -> ResponseContent<R, E2>
+    where
+        F: FnOnce(ResponseError<E>) -> ResponseError<E2>,
+    {
+        match self {
+            ResponseContent::Result(result) => ResponseContent::Result(result),
+            ResponseContent::Error(error) => ResponseContent::Error(f(error)),
+        }
+    }
+
+    pub fn try_map_error<E2, X, F>(self, f: F) -> Result<ResponseContent<R, E2>, X>
+    where
+        F: FnOnce(ResponseError<E>) -> Result<ResponseError<E2>, X>,
+    {
+        match self {
+            ResponseContent::Result(result) => Ok(ResponseContent::Result(result)),
+            ResponseContent::Error(error) => f(error).map(ResponseContent::Error),
+        }
+    }
+}
+
 /// Contents of an **error** response.
 ///
 /// When a rpc call encounters an error, the Response Object MUST contain the
@@ -93,21 +350,347 @@ pub enum ResponseContent<R, E> {
 /// | -32000 to -32099 | Server error | Reserved for implementation-defined server-errors. |
 ///
 /// The remainder of the space is available for application defined errors.
-#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
-pub struct ResponseError<D> {
+///
+/// The **data** type `D` defaults to [`Value`].
+///
+/// New members may be added, so build errors with the constructors and match
+/// with `..`:
+///
+/// ```
+/// use jsonrpc_types::{ErrorCode, ResponseError};
+///
+/// let error = ResponseError::from(ErrorCode::InvalidParams).with_data("p0 is missing");
+///
+/// let ResponseError { code, data, .. } = error;
+/// assert_eq!(code, -32602);
+/// assert_eq!(data, Some("p0 is missing"));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponseError<D = Value> {
     pub code: i64,
-    pub message: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Borrowed for the pre-defined errors, so that they don't allocate.
+    pub message: Cow<'static, str>,
+    /// An absent and a Null **data** member are both `None`, even for
+    /// `D = Value`, so that a Null **data** is accepted for any `D`. `None`
+    /// is omitted when serialized.
     pub data: Option<D>,
+    /// Non-standard members, kept so that they survive a round trip.
+    ///
+    /// Binary formats can't hold arbitrary members, so there they are
+    /// written as a single JSON text.
+    pub extra: Map<String, Value>,
 }
 
-impl<R: Serialize, E: Serialize> Serialize for Response<R, E> {
+impl<D: Serialize> Serialize for ResponseError<D> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
+        if !serializer.is_human_readable() {
+            let extra = match self.extra.is_empty() {
+                true => None,
+                false => {
+                    Some(serde_json::to_string(&self.extra).map_err(serde::ser::Error::custom)?)
+                }
+            };
+
+            return BinaryResponseError {
+                code: self.code,
+                message: &*self.message,
+                data: self.data.as_ref(),
+                extra,
+            }
+            .serialize(serializer);
+        }
+
+        let len = 2 + usize::from(self.data.is_some()) + self.extra.len();
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("code", &self.code)?;
+        map.serialize_entry("message", &self.message)?;
+        if let Some(data) = &self.data {
+            map.serialize_entry("data", data)?;
+        }
+        for (member, value) in &self.extra {
+            map.serialize_entry(member, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, D: Deserialize<'de>> Deserialize<'de> for ResponseError<D> {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            let error = BinaryResponseError::<Cow<'static, str>, D>::deserialize(deserializer)?;
+            let extra = match error.extra {
+                Some(extra) => serde_json::from_str(&extra).map_err(de::Error::custom)?,
+                None => Map::new(),
+            };
+
+            return Ok(Self {
+                code: error.code,
+                message: error.message,
+                data: error.data,
+                extra,
+            });
+        }
+
+        let error = JsonResponseError::deserialize(deserializer)?;
+        Ok(Self {
+            code: error.code,
+            message: error.message,
+            data: error.data,
+            extra: error.extra,
+        })
+    }
+}
+
+/// A [`ResponseError`] in a self-describing format, collecting the
+/// non-standard members.
+#[derive(Deserialize)]
+#[serde(rename = "ResponseError")]
+struct JsonResponseError<D> {
+    code: i64,
+    message: Cow<'static, str>,
+    data: Option<D>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+/// A [`ResponseError`] in a binary format, with its **extra** members as a
+/// JSON text.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "ResponseError")]
+struct BinaryResponseError<M, D> {
+    code: i64,
+    message: M,
+    data: Option<D>,
+    extra: Option<String>,
+}
+
+/// The **extra** members aren't hashed.
+impl<D: Hash> Hash for ResponseError<D> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code.hash(state);
+        self.message.hash(state);
+        self.data.hash(state);
+    }
+}
+
+impl<D> ResponseError<D> {
+    pub fn new(code: impl Into<i64>, message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            data: None,
+            extra: Map::new(),
+        }
+    }
+
+    /// The typed **code**.
+    pub fn error_code(&self) -> ErrorCode {
+        self.code.into()
+    }
+
+    /// Whether the **code** is one of the pre-defined errors.
+    pub fn is_predefined(&self) -> bool {
+        ErrorCode::classify(self.code) == ErrorKind::Predefined
+    }
+
+    /// Whether the **code** is an implementation-defined server-error.
+    pub fn is_server_error(&self) -> bool {
+        ErrorCode::classify(self.code) == ErrorKind::ServerReserved
+    }
+
+    /// Attach **data** to the error.
+    pub fn with_data(self, data: D) -> Self {
+        Self {
+            data: Some(data),
+            ..self
+        }
+    }
+
+    /// Invalid JSON was received by the server.
+    pub fn parse_error() -> Self {
+        Self::predefined(ErrorCode::ParseError)
+    }
+
+    /// The JSON sent is not a valid Request object.
+    pub fn invalid_request() -> Self {
+        Self::predefined(ErrorCode::InvalidRequest)
+    }
+
+    /// The method does not exist / is not available.
+    pub fn method_not_found() -> Self {
+        Self::predefined(ErrorCode::MethodNotFound)
+    }
+
+    /// Invalid method parameter(s).
+    pub fn invalid_params() -> Self {
+        Self::predefined(ErrorCode::InvalidParams)
+    }
+
+    /// Internal JSON-RPC error.
+    pub fn internal_error() -> Self {
+        Self::predefined(ErrorCode::InternalError)
+    }
+
+    /// Implementation-defined server-error.
+    pub fn server_error(code: i64, message: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(code, message)
+    }
+
+    /// Like [`ResponseError::server_error`], but fails if the **code** is
+    /// not within [`SERVER_ERROR_RANGE`].
+    pub fn server_error_checked(
+        code: i64,
+        message: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, Error> {
+        if !SERVER_ERROR_RANGE.contains(&code) {
+            return Err(Error::InvalidServerErrorCode { code });
+        }
+
+        Ok(Self::new(code, message))
+    }
+
+    fn predefined(code: ErrorCode) -> Self {
+        Self::new(code, code.message())
+    }
+}
+
+impl ResponseError<Value> {
+    /// Reparse the **data** as `T`, e.g. a struct with the fields a Server
+    /// usually sends.
+    pub fn data_as<T: DeserializeOwned>(&self) -> Option<Result<T, Error>> {
+        self.data
+            .as_ref()
+            .map(|data| T::deserialize(data).map_err(Error::from))
+    }
+}
+
+/// A [`ResponseError`] which also accepts a **code** String holding an
+/// integer, e.g. `"-32601"`, as sent by some broken Servers.
+///
+/// Serializes the same as its [`ResponseError`], with an integer **code**.
+///
+/// To accept such an **error** in a whole Response, see
+/// [`Response::from_json_str_lenient`].
+#[derive(Serialize, PartialEq, Eq, Hash, Debug, Clone)]
+#[serde(transparent)]
+pub struct LenientResponseError<D = Value>(pub ResponseError<D>);
+
+impl<'de, D: DeserializeOwned> Deserialize<'de> for LenientResponseError<D> {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        // a binary **code** is always an integer
+        if !deserializer.is_human_readable() {
+            return ResponseError::deserialize(deserializer).map(Self);
+        }
+
+        let mut object = Map::deserialize(deserializer)?;
+        parse_lenient_code(&mut object)?;
+
+        ResponseError::deserialize(Value::Object(object))
+            .map(Self)
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Replace a **code** String holding an integer by that integer.
+fn parse_lenient_code<E: de::Error>(error: &mut Map<String, Value>) -> Result<(), E> {
+    if let Some(Value::String(code)) = error.get("code") {
+        let code: i64 = code
+            .trim()
+            .parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(code), &"an integer code"))?;
+        error.insert("code".into(), code.into());
+    }
+
+    Ok(())
+}
+
+impl<D> From<LenientResponseError<D>> for ResponseError<D> {
+    fn from(error: LenientResponseError<D>) -> Self {
+        error.0
+    }
+}
+
+/// An error with the canonical **message** of the code.
+impl<D> From<ErrorCode> for ResponseError<D> {
+    fn from(code: ErrorCode) -> Self {
+        Self::predefined(code)
+    }
+}
+
+/// Defaults to an **Internal error**, since there is no meaningful "empty" error.
+impl<D> Default for ResponseError<D> {
+    fn default() -> Self {
+        Self::internal_error()
+    }
+}
+
+impl<D> fmt::Display for ResponseError<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "JSON-RPC error {}: {}", self.code, self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D: fmt::Debug> std::error::Error for ResponseError<D> {}
+
+#[cfg(feature = "std")]
+impl<D> ResponseError<D> {
+    /// An **Internal error** with the [`Display`](fmt::Display) of `error` as
+    /// its **message**.
+    ///
+    /// Also accepts unsized errors, e.g. `&*Box<dyn Error>`.
+    pub fn from_error<E: std::error::Error + ?Sized>(error: &E) -> Self {
+        Self::new(ErrorCode::InternalError, error.to_string())
+    }
+}
+
+/// Conversion of a handler's failure into a [`ResponseError`].
+///
+/// Any error becomes an **Internal error**, see [`ResponseError::from_error`].
+#[cfg(feature = "std")]
+pub trait IntoResponseError {
+    fn into_response_error<D>(self) -> ResponseError<D>;
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error> IntoResponseError for E {
+    fn into_response_error<D>(self) -> ResponseError<D> {
+        ResponseError::from_error(&self)
+    }
+}
+
+impl<R: Serialize, E: Serialize, I: Serialize> Response<R, E, I> {
+    fn serialize_ordered<S>(&self, serializer: S, id_first: bool) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if !serializer.is_human_readable() {
+            // see `Request::serialize_ordered`
+            let mut state = serializer.serialize_struct("Response", 2)?;
+            state.serialize_field("content", &self.content)?;
+            state.serialize_field("id", &self.id)?;
+            return state.end();
+        }
+
         let mut state = serializer.serialize_struct("Response", 3)?;
-        state.serialize_field("jsonrpc", JSONRPC_V2)?;
+        if id_first {
+            state.serialize_field("id", &self.id)?;
+        }
+        if ser::is_none(&self.jsonrpc) {
+            state.skip_field("jsonrpc")?;
+        } else {
+            state.serialize_field("jsonrpc", &self.jsonrpc)?;
+        }
 
         // flatten result
         match &self.content {
@@ -115,16 +698,240 @@ impl<R: Serialize, E: Serialize> Serialize for Response<R, E> {
             ResponseContent::Error(e) => state.serialize_field("error", e)?,
         }
 
-        state.serialize_field("id", &self.id)?;
+        if !id_first {
+            state.serialize_field("id", &self.id)?;
+        }
         state.end()
     }
 }
 
+impl<R: Serialize, E: Serialize, I: Serialize> Serialize for Response<R, E, I> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.serialize_ordered(serializer, false)
+    }
+}
+
+impl<R: Serialize, E: Serialize, I: Serialize> Serialize for IdFirst<&Response<R, E, I>> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize_ordered(serializer, true)
+    }
+}
+
+impl<'de, R, E, I> Deserialize<'de> for Response<R, E, I>
+where
+    R: Deserialize<'de>,
+    E: Deserialize<'de>,
+    I: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            Jsonrpc,
+            Result,
+            Error,
+            Id,
+            #[serde(other)]
+            Other,
+        }
+
+        struct ResponseVisitor<R, E, I>(PhantomData<(R, E, I)>);
+
+        impl<'de, R, E, I> de::Visitor<'de> for ResponseVisitor<R, E, I>
+        where
+            R: Deserialize<'de>,
+            E: Deserialize<'de>,
+            I: Deserialize<'de>,
+        {
+            type Value = Response<R, E, I>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a JSON-RPC Response object")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let mut jsonrpc = None;
+                let mut result = None;
+                let mut error = None;
+                let mut id = None;
+
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Jsonrpc => {
+                            if jsonrpc.is_some() {
+                                return Err(de::Error::duplicate_field("jsonrpc"));
+                            }
+                            jsonrpc = Some(map.next_value()?);
+                        }
+                        Field::Result => {
+                            if result.is_some() {
+                                return Err(de::Error::duplicate_field("result"));
+                            }
+                            // a Null result is still present, e.g. for `R = ()`
+                            result = Some(map.next_value()?);
+                        }
+                        Field::Error => {
+                            if error.is_some() {
+                                return Err(de::Error::duplicate_field("error"));
+                            }
+                            error = Some(map.next_value()?);
+                        }
+                        Field::Id => {
+                            if id.is_some() {
+                                return Err(de::Error::duplicate_field("id"));
+                            }
+                            id = Some(map.next_value()?);
+                        }
+                        Field::Other => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                let jsonrpc = match jsonrpc {
+                    Some(jsonrpc) => jsonrpc,
+                    None => crate::de::missing_version()?,
+                };
+                let content = match (result, error) {
+                    (Some(result), None) => ResponseContent::Result(result),
+                    (None, Some(error)) => ResponseContent::Error(error),
+                    (Some(_), Some(_)) => return Err(de::Error::custom(Error::BothResultAndError)),
+                    (None, None) => return Err(de::Error::custom(Error::NeitherResultNorError)),
+                };
+                let id = id.ok_or_else(|| de::Error::missing_field("id"))?;
+
+                Ok(Response {
+                    jsonrpc,
+                    content,
+                    id,
+                })
+            }
+        }
+
+        /// A Response in a binary format, see [`Version`].
+        #[derive(Deserialize)]
+        #[serde(rename = "Response")]
+        struct BinaryResponse<R, E, I> {
+            content: ResponseContent<R, E>,
+            id: Option<I>,
+        }
+
+        if !deserializer.is_human_readable() {
+            let BinaryResponse { content, id } = BinaryResponse::deserialize(deserializer)?;
+            return Ok(Response::new(content, id));
+        }
+
+        deserializer.deserialize_struct(
+            "Response",
+            &["jsonrpc", "result", "error", "id"],
+            ResponseVisitor(PhantomData),
+        )
+    }
+}
+
+impl<R, E, I> TryFrom<Value> for Response<R, E, I>
+where
+    R: DeserializeOwned,
+    E: DeserializeOwned,
+    I: DeserializeOwned,
+{
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+impl<R, E, I> Response<R, E, I> {
+    /// Parse a Response from a JSON string.
+    ///
+    /// ```
+    /// use jsonrpc_types::Response;
+    ///
+    /// let json = r#"{"jsonrpc":"2.0","result":19,"id":1}"#;
+    /// let response = Response::<i32, ()>::from_json_str(json)?;
+    ///
+    /// assert_eq!(response.result(), Some(&19));
+    /// assert_eq!(response.to_json_string()?, json);
+    /// # Ok::<(), jsonrpc_types::Error>(())
+    /// ```
+    pub fn from_json_str(s: &str) -> Result<Self, Error>
+    where
+        R: DeserializeOwned,
+        E: DeserializeOwned,
+        I: DeserializeOwned,
+    {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// Like [`Response::from_json_str`], but fails on members other than
+    /// **jsonrpc**, **result**, **error** and **id**.
+    pub fn from_json_str_strict(s: &str) -> Result<Self, Error>
+    where
+        R: DeserializeOwned,
+        E: DeserializeOwned,
+        I: DeserializeOwned,
+    {
+        crate::de::from_str_strict(s, &["jsonrpc", "result", "error", "id"])
+    }
+
+    /// Like [`Response::from_json_str`], but also accepts an **error** with
+    /// a **code** String holding an integer, see [`LenientResponseError`].
+    ///
+    /// ```
+    /// use jsonrpc_types::{Response, ResponseError};
+    ///
+    /// let json = r#"{"jsonrpc":"2.0","error":{"code":"-32601","message":"Method not found"},"id":1}"#;
+    /// let response = Response::<i32, ()>::from_json_str_lenient(json)?;
+    ///
+    /// assert_eq!(response.error(), Some(&ResponseError::method_not_found()));
+    /// # Ok::<(), jsonrpc_types::Error>(())
+    /// ```
+    pub fn from_json_str_lenient(s: &str) -> Result<Self, Error>
+    where
+        R: DeserializeOwned,
+        E: DeserializeOwned,
+        I: DeserializeOwned,
+    {
+        let mut value: Value = serde_json::from_str(s)?;
+        if let Some(Value::Object(error)) = value.get_mut("error") {
+            parse_lenient_code::<serde_json::Error>(error)?;
+        }
+
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Serialize the Response as a JSON string.
+    pub fn to_json_string(&self) -> Result<String, Error>
+    where
+        R: Serialize,
+        E: Serialize,
+        I: Serialize,
+    {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::test_utils::{snapshot, Params};
+    use crate::{
+        test_utils::{positional, snapshot, NestedParams, Params},
+        Message,
+    };
 
     #[test]
     fn test_response_serde() {
@@ -132,40 +939,47 @@ mod tests {
             ($data:expr) => {
                 snapshot!(Response::new(
                     ResponseContent::<_, ()>::Result($data),
-                    Some(1)
+                    Some(Id::from(1))
+                ));
+                snapshot!(Response::new(
+                    ResponseContent::<_, ()>::Result($data),
+                    None::<Id>
                 ));
-                snapshot!(Response::new(ResponseContent::<_, ()>::Result($data), None));
                 snapshot!(Response::new(
                     ResponseContent::<(), _>::Error(ResponseError {
                         code: -1,
-                        message: "message".to_string(),
-                        data: Some($data)
+                        message: "message".into(),
+                        data: Some($data),
+                        extra: Map::new(),
                     }),
-                    Some(1)
+                    Some(Id::from(1))
                 ));
                 snapshot!(Response::new(
                     ResponseContent::<(), ()>::Error(ResponseError {
                         code: -1,
-                        message: "message".to_string(),
-                        data: None
+                        message: "message".into(),
+                        data: None,
+                        extra: Map::new(),
                     }),
-                    Some(1)
+                    Some(Id::from(1))
                 ));
                 snapshot!(Response::new(
                     ResponseContent::<(), _>::Error(ResponseError {
                         code: -1,
-                        message: "message".to_string(),
-                        data: Some($data)
+                        message: "message".into(),
+                        data: Some($data),
+                        extra: Map::new(),
                     }),
-                    None
+                    None::<Id>
                 ));
                 snapshot!(Response::new(
                     ResponseContent::<(), ()>::Error(ResponseError {
                         code: -1,
-                        message: "message".to_string(),
-                        data: None
+                        message: "message".into(),
+                        data: None,
+                        extra: Map::new(),
                     }),
-                    None
+                    None::<Id>
                 ));
             };
         }
@@ -174,4 +988,664 @@ mod tests {
         snapshot_permutations!(vec![1, -1]);
         snapshot_permutations!(Params { p0: 0, p1: 1 });
     }
+
+    #[test]
+    fn test_response_nested_params() {
+        snapshot!(Response::new(
+            ResponseContent::<_, ()>::Result(NestedParams::sample()),
+            Some(Id::from(1))
+        ));
+        snapshot!(Response::new(
+            ResponseContent::<(), _>::Error(
+                ResponseError::internal_error().with_data(NestedParams::sample())
+            ),
+            Some(Id::from(1))
+        ));
+    }
+
+    #[test]
+    fn test_response_content_flattened() {
+        for content in [
+            ResponseContent::Result(Params { p0: 0, p1: 1 }),
+            ResponseContent::Error(ResponseError::invalid_params().with_data(1)),
+        ] {
+            snapshot!(content.clone());
+
+            let mut response =
+                serde_json::to_value(Response::new(content.clone(), Some(Id::from(1)))).unwrap();
+            let response = response.as_object_mut().unwrap();
+            response.remove("jsonrpc");
+            response.remove("id");
+
+            assert_eq!(
+                Value::Object(response.clone()),
+                serde_json::to_value(&content).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_response_borrowed_raw() {
+        use serde_json::value::RawValue;
+
+        let json = r#"{"jsonrpc":"2.0","result":{"a": [1, 2]},"id":1}"#;
+        let response: Response<&RawValue, &RawValue> = serde_json::from_str(json).unwrap();
+        assert_eq!(response.result().unwrap().get(), r#"{"a": [1, 2]}"#);
+
+        let json = r#"{"jsonrpc":"2.0","error":{"code":1,"message":"m","data":[ 1 ]},"id":1}"#;
+        let response: Response<&RawValue, &RawValue> = serde_json::from_str(json).unwrap();
+        assert_eq!(response.error().unwrap().data.unwrap().get(), "[ 1 ]");
+    }
+
+    #[test]
+    fn test_response_null_id() {
+        let json = r#"{"jsonrpc":"2.0","error":{"code":-32700,"message":"Parse error"},"id":null}"#;
+        let response: Response<(), ()> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response,
+            Response::new(
+                ResponseContent::Error(ResponseError {
+                    code: -32700,
+                    message: "Parse error".into(),
+                    data: None,
+                    extra: Map::new(),
+                }),
+                None
+            )
+        );
+        assert_eq!(serde_json::to_string(&response).unwrap(), json);
+    }
+
+    #[test]
+    fn test_response_null_result() {
+        let json = r#"{"jsonrpc":"2.0","result":null,"id":1}"#;
+
+        let response: Response<(), ()> = serde_json::from_str(json).unwrap();
+        assert_eq!(response.content, ResponseContent::Result(()));
+        assert_eq!(serde_json::to_string(&response).unwrap(), json);
+
+        let response: Response<Option<i32>, ()> = serde_json::from_str(json).unwrap();
+        assert_eq!(response.content, ResponseContent::Result(None));
+        assert_eq!(serde_json::to_string(&response).unwrap(), json);
+
+        let message: Message<(), Option<i32>, ()> = serde_json::from_str(json).unwrap();
+        assert_eq!(message, Message::Response(response));
+    }
+
+    #[test]
+    fn test_response_absent_id() {
+        let json = r#"{"jsonrpc":"2.0","result":1}"#;
+
+        assert!(serde_json::from_str::<Response<i32, ()>>(json).is_err());
+    }
+
+    #[test]
+    fn test_response_id_first() {
+        insta::assert_json_snapshot!(IdFirst(&Response::new(
+            ResponseContent::<_, ()>::Result(Params { p0: 0, p1: 1 }),
+            Some(Id::from(1))
+        )));
+    }
+
+    #[test]
+    fn test_response_binary_format() {
+        for response in [
+            Response::<_, ()>::new(ResponseContent::Result(1), Some(Id::from(1))),
+            Response::new(ResponseContent::Result(2), None),
+        ] {
+            assert_eq!(positional::round_trip(&response).unwrap(), response);
+            assert_eq!(
+                positional::to_tokens(&IdFirst(&response)).unwrap(),
+                positional::to_tokens(&response).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_response_error_binary_format() {
+        let mut with_extra = ResponseError::invalid_params().with_data("p0".to_string());
+        with_extra
+            .extra
+            .insert("trace".into(), serde_json::json!([1, 2]));
+
+        for error in [
+            ResponseError::method_not_found(),
+            ResponseError::new(1, "m").with_data("d".to_string()),
+            with_extra,
+        ] {
+            let response: Response<i32, String> = Response::from_result(Err(error), Some(1.into()));
+            assert_eq!(positional::round_trip(&response).unwrap(), response);
+
+            let lenient: LenientResponseError<String> =
+                LenientResponseError(response.error().unwrap().clone());
+            assert_eq!(positional::round_trip(&lenient).unwrap(), lenient);
+        }
+    }
+
+    #[test]
+    fn test_response_builder() {
+        let response = Response::<_, ()>::success(1).id(1).build();
+        assert_eq!(response.content, ResponseContent::Result(1));
+        assert_eq!(response.id, Some(Id::from(1)));
+
+        let response = Response::<(), _>::failure(ResponseError::<()>::invalid_request()).build();
+        assert_eq!(
+            response.content,
+            ResponseContent::Error(ResponseError::invalid_request())
+        );
+        assert_eq!(response.id, None);
+    }
+
+    #[test]
+    fn test_response_with_id() {
+        let response = Response::new(
+            ResponseContent::<(), ()>::Error(ResponseError::parse_error()),
+            Some(Id::from(5)),
+        );
+        let response = response.with_null_id();
+
+        assert_eq!(response.id, None);
+        assert_eq!(
+            serde_json::to_string(&response).unwrap(),
+            r#"{"jsonrpc":"2.0","error":{"code":-32700,"message":"Parse error"},"id":null}"#
+        );
+        assert_eq!(response.with_id(6).id, Some(Id::from(6)));
+    }
+
+    #[test]
+    fn test_response_validate() {
+        for error in [
+            ResponseError::<()>::method_not_found(),
+            ResponseError::server_error(-32050, "Busy"),
+            ResponseError::new(-32769, "Application"),
+        ] {
+            let response = Response::<(), _>::failure(error).build();
+            assert!(response.validate().is_ok());
+        }
+        assert!(Response::<_, ()>::success(1).build().validate().is_ok());
+
+        let response = Response::<(), _>::failure(ResponseError::<()>::new(1, "")).build();
+        assert!(matches!(response.validate(), Err(Error::EmptyErrorMessage)));
+
+        let response = Response::<(), _>::failure(ResponseError::<()>::new(-32500, "m")).build();
+        assert!(matches!(
+            response.validate(),
+            Err(Error::ReservedErrorCode { code: -32500 })
+        ));
+    }
+
+    #[test]
+    fn test_response_from_error() {
+        let response: Response<i32, ()> = ResponseError::parse_error().into();
+
+        assert_eq!(response.id, None);
+        assert_eq!(
+            serde_json::to_string(&response).unwrap(),
+            r#"{"jsonrpc":"2.0","error":{"code":-32700,"message":"Parse error"},"id":null}"#
+        );
+    }
+
+    #[test]
+    fn test_response_strict() {
+        let json = r#"{"jsonrpc":"2.0","result":1,"id":1,"extra":true}"#;
+
+        assert!(Response::<i32, ()>::from_json_str(json).is_ok());
+        assert!(matches!(
+            Response::<i32, ()>::from_json_str_strict(json),
+            Err(Error::UnknownMember { member }) if member == "extra"
+        ));
+        assert!(Response::<i32, ()>::from_json_str_strict(
+            r#"{"jsonrpc":"2.0","result":1,"id":1}"#
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_response_error_predefined() {
+        for (error, code, message) in [
+            (ResponseError::<()>::parse_error(), -32700, "Parse error"),
+            (ResponseError::invalid_request(), -32600, "Invalid Request"),
+            (
+                ResponseError::method_not_found(),
+                -32601,
+                "Method not found",
+            ),
+            (ResponseError::invalid_params(), -32602, "Invalid params"),
+            (ResponseError::internal_error(), -32603, "Internal error"),
+            (ResponseError::server_error(-32000, "Busy"), -32000, "Busy"),
+            (ErrorCode::MethodNotFound.into(), -32601, "Method not found"),
+        ] {
+            assert_eq!(error.code, code);
+            assert_eq!(error.message, message);
+            assert_eq!(error.data, None);
+            assert!(matches!(error.message, Cow::Borrowed(_)));
+        }
+
+        let error = ResponseError::<()>::new(1, "dynamic".to_string());
+        assert!(matches!(error.message, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_response_error_data() {
+        for (json, data) in [
+            (r#"{"code":1,"message":"m"}"#, None),
+            (r#"{"code":1,"message":"m","data":null}"#, None),
+            (
+                r#"{"code":1,"message":"m","data":[1]}"#,
+                Some(serde_json::json!([1])),
+            ),
+        ] {
+            let error: ResponseError<Value> = serde_json::from_str(json).unwrap();
+            assert_eq!(error.data, data);
+        }
+
+        let json = r#"{"code":1,"message":"m","data":null}"#;
+        let error: ResponseError<i32> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(error.data, None);
+        assert_eq!(
+            serde_json::to_string(&error).unwrap(),
+            r#"{"code":1,"message":"m"}"#
+        );
+    }
+
+    #[test]
+    fn test_response_error_data_as() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Details {
+            details: String,
+            field: String,
+        }
+
+        let error = ResponseError::invalid_params()
+            .with_data(serde_json::json!({"details": "is missing", "field": "p0"}));
+
+        assert_eq!(
+            error.data_as::<Details>().unwrap().unwrap(),
+            Details {
+                details: "is missing".to_string(),
+                field: "p0".to_string(),
+            }
+        );
+        assert!(matches!(
+            error.data_as::<Params>(),
+            Some(Err(Error::Json(_)))
+        ));
+        assert!(ResponseError::invalid_params()
+            .data_as::<Details>()
+            .is_none());
+    }
+
+    #[test]
+    fn test_response_error_server_error_checked() {
+        for code in [-32000, -32099, -32050] {
+            let error = ResponseError::<()>::server_error_checked(code, "Busy").unwrap();
+            assert_eq!(error.code, code);
+            assert!(error.is_server_error());
+        }
+
+        for code in [-31999, -32100, -32600, 1] {
+            assert!(matches!(
+                ResponseError::<()>::server_error_checked(code, "Busy"),
+                Err(Error::InvalidServerErrorCode { code: c }) if c == code
+            ));
+        }
+    }
+
+    #[test]
+    fn test_response_error_extra() {
+        let json = r#"{"code":-32603,"message":"Internal error","data":1,"trace_id":"abc"}"#;
+        let error: ResponseError<i32> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(error.data, Some(1));
+        assert_eq!(error.extra["trace_id"], "abc");
+        assert_eq!(serde_json::to_string(&error).unwrap(), json);
+    }
+
+    #[test]
+    fn test_response_error_classify() {
+        for (code, error_code, predefined, server_error) in [
+            (-32700, ErrorCode::ParseError, true, false),
+            (-32603, ErrorCode::InternalError, true, false),
+            (-32000, ErrorCode::ServerError(-32000), false, true),
+            (-32099, ErrorCode::ServerError(-32099), false, true),
+            (-32100, ErrorCode::Application(-32100), false, false),
+            (42, ErrorCode::Application(42), false, false),
+        ] {
+            let error = ResponseError::<()>::new(code, "message");
+
+            assert_eq!(error.error_code(), error_code);
+            assert_eq!(error.is_predefined(), predefined, "{code}");
+            assert_eq!(error.is_server_error(), server_error, "{code}");
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_response_error_from_error() {
+        let io = || std::io::Error::new(std::io::ErrorKind::NotFound, "no such file");
+
+        let error = ResponseError::<()>::from_error(&io());
+        assert_eq!(error.code, -32603);
+        assert_eq!(error.message, "no such file");
+        assert_eq!(error.data, None);
+
+        assert_eq!(io().into_response_error::<()>(), error);
+
+        let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(io());
+        assert_eq!(ResponseError::from_error(&*boxed), error);
+    }
+
+    #[test]
+    fn test_response_error_with_data() {
+        let error = ResponseError::invalid_params().with_data(Params { p0: 0, p1: 1 });
+
+        assert_eq!(error.code, -32602);
+        assert_eq!(error.data, Some(Params { p0: 0, p1: 1 }));
+    }
+
+    #[test]
+    fn test_response_invalid_version() {
+        for json in [
+            r#"{"jsonrpc":"1.0","result":1,"id":1}"#,
+            r#"{"jsonrpc":2.0,"result":1,"id":1}"#,
+        ] {
+            assert!(serde_json::from_str::<Response<i32, ()>>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn test_response_result_xor_error() {
+        for json in [
+            r#"{"jsonrpc":"2.0","result":1,"error":{"code":-1,"message":"message"},"id":1}"#,
+            r#"{"jsonrpc":"2.0","id":1}"#,
+        ] {
+            let error = serde_json::from_str::<Response<i32, ()>>(json).unwrap_err();
+
+            assert!(error
+                .to_string()
+                .starts_with("response must contain exactly one of result or error"));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_response_error_display() {
+        let error = ResponseError::<()>::method_not_found();
+
+        assert_eq!(error.to_string(), "JSON-RPC error -32601: Method not found");
+
+        let error: Box<dyn std::error::Error> = Box::new(error);
+        assert_eq!(error.to_string(), "JSON-RPC error -32601: Method not found");
+    }
+
+    #[test]
+    fn test_response_accessors() {
+        let response = Response::new(ResponseContent::<_, ()>::Result(1), Some(Id::from(1)));
+
+        assert_eq!(response.result(), Some(&1));
+        assert_eq!(response.error(), None);
+        assert!(!response.is_error());
+        assert_eq!(response.into_result(), Ok(1));
+
+        let response = Response::new(
+            ResponseContent::<i32, ()>::Error(ResponseError::internal_error()),
+            Some(Id::from(1)),
+        );
+
+        assert_eq!(response.result(), None);
+        assert_eq!(response.error(), Some(&ResponseError::internal_error()));
+        assert!(response.is_error());
+        assert_eq!(response.into_result(), Err(ResponseError::internal_error()));
+    }
+
+    #[test]
+    fn test_response_from_result() {
+        assert_eq!(
+            Response::<_, ()>::from_result(Ok(1), Some(Id::from(1))),
+            Response::new(ResponseContent::Result(1), Some(Id::from(1)))
+        );
+        assert_eq!(
+            Response::<i32, ()>::from_result(Err(ResponseError::internal_error()), None),
+            Response::new(
+                ResponseContent::Error(ResponseError::internal_error()),
+                None
+            )
+        );
+
+        let response = Response::<_, ()>::from_result(Ok("a"), Some(Id::from(1)));
+        assert_eq!(
+            Response::from_result(response.clone().into_result(), response.id.clone()),
+            response
+        );
+    }
+
+    #[test]
+    fn test_response_into_parts() {
+        let response = Response::<_, ()>::from_result(Ok(1), Some(Id::from(1)));
+        assert_eq!(response.into_parts(), (Some(Id::from(1)), Ok(1)));
+
+        let response = Response::<i32, ()>::new(
+            ResponseContent::Error(ResponseError::invalid_request()),
+            None,
+        );
+        assert_eq!(
+            response.into_parts(),
+            (None, Err(ResponseError::invalid_request()))
+        );
+    }
+
+    #[test]
+    fn test_response_custom_id() {
+        #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+        struct Uuid(String);
+
+        let response = Response::new(
+            ResponseContent::<_, ()>::Result(1),
+            Some(Uuid("7c9e6679".to_string())),
+        );
+        let json = r#"{"jsonrpc":"2.0","result":1,"id":"7c9e6679"}"#;
+
+        assert_eq!(serde_json::to_string(&response).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<Response<i32, (), Uuid>>(json).unwrap(),
+            response
+        );
+    }
+
+    #[test]
+    fn test_response_error_lenient_code() {
+        let json = r#"{"code":"-32601","message":"Method not found"}"#;
+
+        assert!(serde_json::from_str::<ResponseError<()>>(json).is_err());
+        let error: LenientResponseError<()> = serde_json::from_str(json).unwrap();
+        assert_eq!(error.0, ResponseError::method_not_found());
+        assert_eq!(
+            serde_json::to_string(&error).unwrap(),
+            r#"{"code":-32601,"message":"Method not found"}"#
+        );
+
+        let json = r#"{"code":-32601,"message":"Method not found","data":1}"#;
+        let error: LenientResponseError = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            ResponseError::from(error),
+            ResponseError::method_not_found().with_data(1.into())
+        );
+
+        for json in [
+            r#"{"code":"-32601.5","message":"m"}"#,
+            r#"{"code":"abc","message":"m"}"#,
+        ] {
+            assert!(serde_json::from_str::<LenientResponseError<()>>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn test_response_from_json_str_lenient() {
+        let json = r#"{"jsonrpc":"2.0","error":{"code":" -32601","message":"Method not found","data":1},"id":"1"}"#;
+
+        assert!(Response::<i32>::from_json_str(json).is_err());
+        let response = Response::<i32>::from_json_str_lenient(json).unwrap();
+        assert_eq!(
+            response,
+            Response::new(
+                ResponseContent::Error(ResponseError::method_not_found().with_data(1.into())),
+                Some(Id::from("1"))
+            )
+        );
+        assert_eq!(
+            response.to_json_string().unwrap(),
+            r#"{"jsonrpc":"2.0","error":{"code":-32601,"message":"Method not found","data":1},"id":"1"}"#
+        );
+
+        let json = r#"{"jsonrpc":"2.0","result":19,"id":1}"#;
+        assert_eq!(
+            Response::<i32, ()>::from_json_str_lenient(json).unwrap(),
+            Response::<i32, ()>::from_json_str(json).unwrap()
+        );
+
+        for json in [
+            r#"{"jsonrpc":"2.0","error":{"code":"abc","message":"m"},"id":1}"#,
+            r#"{"jsonrpc":"2.0","result":1,"error":{"code":"1","message":"m"},"id":1}"#,
+        ] {
+            assert!(Response::<i32, ()>::from_json_str_lenient(json).is_err());
+        }
+    }
+
+    #[test]
+    fn test_response_error_default() {
+        let error = ResponseError::<()>::default();
+
+        assert_eq!(error.code, -32603);
+        assert_eq!(error.message, "Internal error");
+        assert_eq!(error.data, None);
+    }
+
+    #[test]
+    fn test_response_try_map_error() {
+        fn reparse(e: ResponseError<Value>) -> Result<ResponseError<Params>, serde_json::Error> {
+            Ok(ResponseError {
+                code: e.code,
+                message: e.message,
+                data: e.data.map(serde_json::from_value).transpose()?,
+                extra: e.extra,
+            })
+        }
+
+        let response = Response::<i32, Value>::new(
+            ResponseContent::Error(
+                ResponseError::internal_error().with_data(serde_json::json!({"p0":0,"p1":1})),
+            ),
+            Some(Id::from(1)),
+        );
+
+        assert_eq!(
+            response.try_map_error(reparse).unwrap(),
+            Response::new(
+                ResponseContent::Error(
+                    ResponseError::internal_error().with_data(Params { p0: 0, p1: 1 })
+                ),
+                Some(Id::from(1))
+            )
+        );
+
+        let response = Response::<i32, Value>::new(
+            ResponseContent::Error(ResponseError::internal_error().with_data(Value::from(1))),
+            Some(Id::from(1)),
+        );
+
+        assert!(response.try_map_error(reparse).is_err());
+
+        let response = Response::<_, Value>::new(ResponseContent::Result(1), Some(Id::from(1)));
+
+        assert_eq!(
+            response.try_map_error(reparse).unwrap(),
+            Response::new(ResponseContent::Result(1), Some(Id::from(1)))
+        );
+    }
+
+    #[test]
+    fn test_response_content_accessors() {
+        let content = ResponseContent::<_, ()>::Result(5);
+
+        assert_eq!(content.as_result(), Some(&5));
+        assert_eq!(content.as_error(), None);
+
+        let content = ResponseContent::<i32, ()>::Error(ResponseError::method_not_found());
+
+        assert_eq!(content.as_result(), None);
+        assert_eq!(content.as_error(), Some(&ResponseError::method_not_found()));
+    }
+
+    #[test]
+    fn test_response_content_into_result() {
+        fn double(content: ResponseContent<i32, ()>) -> Result<i32, ResponseError<()>> {
+            Ok(content.into_result()? * 2)
+        }
+
+        assert_eq!(double(ResponseContent::Result(2)), Ok(4));
+        assert_eq!(
+            double(ResponseContent::Error(ResponseError::invalid_params())),
+            Err(ResponseError::invalid_params())
+        );
+    }
+
+    #[test]
+    fn test_response_map() {
+        let response = Response::new(ResponseContent::<_, ()>::Result(1), Some(Id::from(1)));
+
+        assert_eq!(
+            response.clone().map_result(|r| r.to_string()),
+            Response::new(ResponseContent::Result("1".to_string()), Some(Id::from(1)))
+        );
+        assert_eq!(
+            response.map_error(|_| -> ResponseError<()> { unreachable!() }),
+            Response::new(ResponseContent::Result(1), Some(Id::from(1)))
+        );
+
+        let response = Response::new(
+            ResponseContent::<i32, _>::Error(ResponseError::internal_error().with_data(1)),
+            Some(Id::from(1)),
+        );
+
+        assert_eq!(
+            response.clone().map_error(|e| ResponseError {
+                code: e.code,
+                message: e.message,
+                data: e.data.map(|d| Params { p0: d, p1: d }),
+                extra: e.extra,
+            }),
+            Response::new(
+                ResponseContent::Error(
+                    ResponseError::internal_error().with_data(Params { p0: 1, p1: 1 })
+                ),
+                Some(Id::from(1))
+            )
+        );
+        assert_eq!(
+            response.clone().map_result(|r| r.to_string()),
+            Response::new(
+                ResponseContent::Error(response.error().unwrap().clone()),
+                Some(Id::from(1))
+            )
+        );
+    }
+
+    #[test]
+    fn test_response_try_from_value() {
+        let value = serde_json::json!({"jsonrpc": "2.0", "result": 1, "id": 1});
+
+        assert_eq!(
+            Response::try_from(value).unwrap(),
+            Response::new(ResponseContent::<_, ()>::Result(1), Some(Id::from(1)))
+        );
+
+        let value = serde_json::json!({"jsonrpc": "2.0", "id": 1});
+
+        assert!(matches!(
+            Response::<i32, ()>::try_from(value),
+            Err(Error::NeitherResultNorError)
+        ));
+    }
 }
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use crate::{Message, Notification, Request, Response, ResponseError};
+
+type BoxedHandler<P, R, E> = Box<dyn Fn(&P) -> Result<R, ResponseError<E>> + Send + Sync>;
+
+/// Dispatches calls to the handlers registered for their **method**.
+///
+/// Unlike [`Request::dispatch`], the handlers are owned, so that a server can
+/// build its router once.
+///
+/// ```
+/// use jsonrpc_types::{Id, Request, ResponseError, Router};
+///
+/// let mut router = Router::<Vec<i32>, i32>::new();
+/// router.register("add", |p| Ok(p.iter().sum()));
+/// router.register("neg", |p| {
+///     p.first().map(|n| -n).ok_or_else(ResponseError::invalid_params)
+/// });
+///
+/// let request = Request::new("add", vec![1, 2], Some(Id::from(1)));
+/// assert_eq!(router.handle_request(&request).result(), Some(&3));
+/// ```
+pub struct Router<P, R, E = ()> {
+    handlers: HashMap<String, BoxedHandler<P, R, E>>,
+}
+
+impl<P, R, E> Router<P, R, E> {
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+        }
+    }
+
+    /// Register the handler of `method`, replacing any previous one.
+    pub fn register<F>(&mut self, method: impl Into<String>, handler: F) -> &mut Self
+    where
+        F: Fn(&P) -> Result<R, ResponseError<E>> + Send + Sync + 'static,
+    {
+        self.handlers.insert(method.into(), Box::new(handler));
+        self
+    }
+
+    /// Whether a handler is registered for `method`.
+    pub fn contains(&self, method: &str) -> bool {
+        self.handlers.contains_key(method)
+    }
+
+    /// Call the handler of the Request, replying with its outcome, or with a
+    /// **Method not found** error if there is none.
+    pub fn handle_request<I: Clone>(&self, request: &Request<'_, P, I>) -> Response<R, E, I> {
+        match self.handlers.get(request.method.as_ref()) {
+            Some(handler) => match handler(&request.params) {
+                Ok(result) => request.success_response(result),
+                Err(error) => request.error_response(error),
+            },
+            None => request.error_response(ResponseError::method_not_found()),
+        }
+    }
+
+    /// Call the handler of the Notification, if there is one.
+    ///
+    /// The Server MUST NOT reply to a Notification, so its outcome is
+    /// discarded.
+    pub fn handle_notification(&self, notification: &Notification<'_, P>) {
+        if let Some(handler) = self.handlers.get(notification.method.as_ref()) {
+            let _ = handler(&notification.params);
+        }
+    }
+
+    /// Handle a Request or Notification, returning the Response to a Request.
+    ///
+    /// Responses are ignored, since they aren't calls.
+    pub fn handle<I: Clone>(&self, message: &Message<'_, P, R, E, I>) -> Option<Response<R, E, I>> {
+        match message {
+            Message::Request(request) => Some(self.handle_request(request)),
+            Message::Notification(notification) => {
+                self.handle_notification(notification);
+                None
+            }
+            Message::Response(_) => None,
+        }
+    }
+}
+
+impl<P, R, E> Default for Router<P, R, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use super::*;
+
+    use crate::{Id, ResponseContent};
+
+    fn router() -> Router<Vec<i32>, i32> {
+        let mut router = Router::new();
+        router
+            .register("add", |p: &Vec<i32>| Ok(p.iter().sum()))
+            .register("first", |p: &Vec<i32>| {
+                p.first().copied().ok_or_else(ResponseError::invalid_params)
+            });
+        router
+    }
+
+    #[test]
+    fn test_router_requests() {
+        let router = router();
+
+        assert!(router.contains("add"));
+        assert!(!router.contains("sub"));
+        assert_eq!(
+            router.handle_request(&Request::new("add", vec![1, 2], Some(Id::from(1)))),
+            Response::new(ResponseContent::Result(3), Some(Id::from(1)))
+        );
+        assert_eq!(
+            router.handle_request(&Request::new("first", vec![], Some(Id::from(2)))),
+            Response::new(
+                ResponseContent::Error(ResponseError::invalid_params()),
+                Some(Id::from(2))
+            )
+        );
+        assert_eq!(
+            router.handle_request(&Request::new("sub", vec![1, 2], Some(Id::from(3)))),
+            Response::new(
+                ResponseContent::Error(ResponseError::method_not_found()),
+                Some(Id::from(3))
+            )
+        );
+    }
+
+    #[test]
+    fn test_router_notifications() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut router = Router::<(), ()>::new();
+        router.register("notify", {
+            let calls = calls.clone();
+            move |_| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            }
+        });
+
+        let notification = Message::<_, _, (), Id>::Notification(Notification::new("notify", ()));
+
+        assert_eq!(router.handle(&notification), None);
+        router.handle_notification(&Notification::new("unknown", ()));
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        let request = Message::Request(Request::new("notify", (), Some(Id::from(1))));
+
+        assert_eq!(
+            router.handle(&request),
+            Some(Response::new(
+                ResponseContent::Result(()),
+                Some(Id::from(1))
+            ))
+        );
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+}
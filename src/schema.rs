@@ -0,0 +1,220 @@
+use alloc::{borrow::Cow, format};
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{Id, Notification, Request, Response, ResponseError, JSONRPC_VERSION};
+
+/// A String or an integer Number, the Null id being the absence of an
+/// [`Id`].
+impl JsonSchema for Id {
+    fn schema_name() -> Cow<'static, str> {
+        "Id".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "jsonrpc_types::Id".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": ["string", "integer"],
+        })
+    }
+}
+
+/// The **id** isn't required, since a Request without an id (`None`)
+/// serializes without it.
+impl<P: JsonSchema, I: JsonSchema> JsonSchema for Request<'_, P, I> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("Request_for_{}_and_{}", P::schema_name(), I::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!(
+            "jsonrpc_types::Request<{}, {}>",
+            P::schema_id(),
+            I::schema_id()
+        )
+        .into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "object",
+            "properties": {
+                "jsonrpc": { "const": JSONRPC_VERSION },
+                "method": { "type": "string" },
+                "params": generator.subschema_for::<P>(),
+                "id": generator.subschema_for::<Option<I>>(),
+            },
+            "required": ["jsonrpc", "method"],
+        })
+    }
+}
+
+/// A Notification is a Request without an **id** member.
+impl<P: JsonSchema> JsonSchema for Notification<'_, P> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("Notification_for_{}", P::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("jsonrpc_types::Notification<{}>", P::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "object",
+            "properties": {
+                "jsonrpc": { "const": JSONRPC_VERSION },
+                "method": { "type": "string" },
+                "params": generator.subschema_for::<P>(),
+            },
+            "required": ["jsonrpc", "method"],
+            "not": { "required": ["id"] },
+        })
+    }
+}
+
+/// Exactly one of **result** and **error**, expressed with a `oneOf`.
+impl<R: JsonSchema, E: JsonSchema, I: JsonSchema> JsonSchema for Response<R, E, I> {
+    fn schema_name() -> Cow<'static, str> {
+        format!(
+            "Response_for_{}_and_{}_and_{}",
+            R::schema_name(),
+            E::schema_name(),
+            I::schema_name()
+        )
+        .into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!(
+            "jsonrpc_types::Response<{}, {}, {}>",
+            R::schema_id(),
+            E::schema_id(),
+            I::schema_id()
+        )
+        .into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "object",
+            "properties": {
+                "jsonrpc": { "const": JSONRPC_VERSION },
+                "id": generator.subschema_for::<Option<I>>(),
+            },
+            "required": ["jsonrpc", "id"],
+            "oneOf": [
+                {
+                    "properties": { "result": generator.subschema_for::<R>() },
+                    "required": ["result"],
+                },
+                {
+                    "properties": { "error": generator.subschema_for::<ResponseError<E>>() },
+                    "required": ["error"],
+                },
+            ],
+        })
+    }
+}
+
+impl<D: JsonSchema> JsonSchema for ResponseError<D> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("ResponseError_for_{}", D::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("jsonrpc_types::ResponseError<{}>", D::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "object",
+            "properties": {
+                "code": { "type": "integer", "format": "int64" },
+                "message": { "type": "string" },
+                "data": generator.subschema_for::<D>(),
+            },
+            "required": ["code", "message"],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use schemars::schema_for;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_response_schema() {
+        let schema = schema_for!(Response<i32, Value>);
+
+        let one_of = schema.get("oneOf").unwrap().as_array().unwrap();
+        assert_eq!(one_of.len(), 2);
+        assert_eq!(one_of[0]["required"], json!(["result"]));
+        assert_eq!(one_of[1]["required"], json!(["error"]));
+
+        insta::assert_json_snapshot!(schema);
+    }
+
+    #[test]
+    fn test_response_error_schema() {
+        let schema = schema_for!(ResponseError);
+
+        assert_eq!(schema.get("properties").unwrap()["code"]["type"], "integer");
+        assert_eq!(schema.get("required").unwrap(), &json!(["code", "message"]));
+    }
+
+    /// Whether `value` serializes to a JSON instance of the schema of `T`.
+    fn is_valid<T: JsonSchema + serde::Serialize>(value: &T) -> bool {
+        is_instance_of::<T>(serde_json::to_value(value).unwrap())
+    }
+
+    fn is_instance_of<T: JsonSchema>(instance: Value) -> bool {
+        let schema = serde_json::to_value(schema_for!(T)).unwrap();
+
+        jsonschema::validator_for(&schema)
+            .unwrap()
+            .is_valid(&instance)
+    }
+
+    #[test]
+    fn test_schema_accepts_serialized() {
+        assert!(is_valid(&Request::new("m", vec![1], Some(Id::from(1)))));
+        assert!(is_valid(&Request::new("m", vec![1], Some(Id::from("a")))));
+        assert!(is_valid(&Request::new("m", vec![1], None::<Id>)));
+        assert!(is_valid(&Notification::new("m", vec![1])));
+        assert!(is_valid(&Response::<i32, Value>::from_result(
+            Ok(1),
+            Some(Id::from(1))
+        )));
+        assert!(is_valid(&Response::<i32, Value>::from_result(
+            Err(ResponseError::method_not_found()),
+            None
+        )));
+
+        for instance in [
+            json!({"jsonrpc": "2.0", "method": 1, "params": [1], "id": 1}),
+            json!({"jsonrpc": "1.0", "method": "m", "params": [1], "id": 1}),
+            json!({"jsonrpc": "2.0", "params": [1], "id": 1}),
+        ] {
+            assert!(!is_instance_of::<Request<Vec<i32>>>(instance));
+        }
+        assert!(!is_instance_of::<Notification<Vec<i32>>>(
+            json!({"jsonrpc": "2.0", "method": "m", "params": [1], "id": 1})
+        ));
+        assert!(!is_instance_of::<Response<i32, Value>>(
+            json!({"jsonrpc": "2.0", "result": 1, "error": {"code": 1, "message": "m"}, "id": 1})
+        ));
+    }
+
+    #[test]
+    fn test_request_schema() {
+        insta::assert_json_snapshot!(schema_for!(Request<Vec<i32>>));
+        insta::assert_json_snapshot!(schema_for!(Notification<Value>));
+    }
+}
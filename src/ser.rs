@@ -0,0 +1,179 @@
+//! Serialization helpers.
+
+use core::fmt;
+
+use serde::{ser, Serialize};
+
+/// Serializes a [`Request`](crate::Request) or [`Response`](crate::Response)
+/// with the **id** member first, for peers expecting that order.
+///
+/// ```
+/// use jsonrpc_types::{Id, IdFirst, Request};
+///
+/// let request = Request::new("m", [1], Some(Id::from(1)));
+///
+/// assert_eq!(
+///     serde_json::to_string(&IdFirst(&request)).unwrap(),
+///     r#"{"id":1,"jsonrpc":"2.0","method":"m","params":[1]}"#
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct IdFirst<T>(pub T);
+
+/// Check whether `value` serializes as `None`, without serializing it.
+///
+/// Used to omit members that MAY be omitted (e.g. **params**) when their
+/// value is `None`.
+pub(crate) fn is_none<T: Serialize + ?Sized>(value: &T) -> bool {
+    value.serialize(IsNone).is_ok()
+}
+
+/// A [`Serializer`](ser::Serializer) which succeeds only for `None`.
+struct IsNone;
+
+#[derive(Debug)]
+struct NotNone;
+
+impl fmt::Display for NotNone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value is not None")
+    }
+}
+
+impl ser::StdError for NotNone {}
+
+impl ser::Error for NotNone {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        NotNone
+    }
+}
+
+macro_rules! not_none {
+    ($($method:ident($($ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $ty),*) -> Result<Self::Ok, Self::Error> {
+                Err(NotNone)
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for IsNone {
+    type Ok = ();
+    type Error = NotNone;
+    type SerializeSeq = ser::Impossible<(), NotNone>;
+    type SerializeTuple = ser::Impossible<(), NotNone>;
+    type SerializeTupleStruct = ser::Impossible<(), NotNone>;
+    type SerializeTupleVariant = ser::Impossible<(), NotNone>;
+    type SerializeMap = ser::Impossible<(), NotNone>;
+    type SerializeStruct = ser::Impossible<(), NotNone>;
+    type SerializeStructVariant = ser::Impossible<(), NotNone>;
+
+    not_none! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<Self::Ok, Self::Error> {
+        Err(NotNone)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(NotNone)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(NotNone)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(NotNone)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(NotNone)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(NotNone)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(NotNone)
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(NotNone)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(NotNone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_none() {
+        assert!(is_none(&None::<()>));
+        assert!(is_none(&None::<Vec<u32>>));
+        assert!(!is_none(&Some(())));
+        assert!(!is_none(&()));
+        assert!(!is_none(&vec![0, 1]));
+    }
+}
@@ -0,0 +1,248 @@
+use alloc::string::ToString;
+use core::fmt;
+
+use serde::{de, Deserialize, Serialize};
+
+use crate::{Error, JSONRPC_VERSION};
+
+/// The version of the JSON-RPC protocol of an object.
+///
+/// 2.0 always has a member named "jsonrpc" with a String value of "2.0"
+/// whereas 1.0 does not.
+///
+/// Objects lacking the **jsonrpc** member are only accepted as [`Version::V1`]
+/// with the `v1` feature. [`Version::V1`] serializes as `None`, so that the
+/// **jsonrpc** member is omitted.
+///
+/// Non human-readable (binary) formats never contain the **jsonrpc** member,
+/// and objects deserialized from them are [`Version::V2`]. Since binary
+/// formats (e.g. bincode) are positional, objects are written with a fixed
+/// set of fields in them: **method**, **params** and **id** for a
+/// [`Request`](crate::Request), and the [`ResponseContent`](crate::ResponseContent)
+/// and **id** for a [`Response`](crate::Response). The **params**,
+/// **result**, **data** and **id** types must then not require a
+/// self-describing format, which rules out [`Value`](serde_json::Value) and
+/// [`Params`](crate::Params).
+///
+/// It is the public `jsonrpc` field of [`Request`](crate::Request),
+/// [`Notification`](crate::Notification) and [`Response`](crate::Response):
+///
+/// ```
+/// use jsonrpc_types::{Id, Request, Version};
+///
+/// let request = Request::new("m", [1], Some(Id::from(1)));
+/// assert_eq!(request.jsonrpc, Version::V2);
+/// assert_eq!(
+///     request.to_json_string()?,
+///     r#"{"jsonrpc":"2.0","method":"m","params":[1],"id":1}"#
+/// );
+///
+/// let json = r#"{"jsonrpc":"1.5","method":"m","params":[1],"id":1}"#;
+/// assert!(Request::<[i32; 1]>::from_json_str(json).is_err());
+/// # Ok::<(), jsonrpc_types::Error>(())
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub enum Version {
+    V1,
+    #[default]
+    V2,
+}
+
+impl Serialize for Version {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Version::V1 => serializer.serialize_none(),
+            Version::V2 => serializer.serialize_str(JSONRPC_VERSION),
+        }
+    }
+}
+
+/// Only "2.0" is accepted, a missing **jsonrpc** member is handled by the
+/// containing object.
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct VersionVisitor;
+
+        impl<'de> de::Visitor<'de> for VersionVisitor {
+            type Value = Version;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "\"{JSONRPC_VERSION}\"")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                if v == JSONRPC_VERSION {
+                    Ok(Version::V2)
+                } else {
+                    Err(invalid_version(format_args!("{v:?}")))
+                }
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Err(invalid_version(v))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Err(invalid_version(v))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Err(invalid_version(format_args!("{v:?}")))
+            }
+
+            fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+                Err(invalid_version(v))
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Err(invalid_version("null"))
+            }
+        }
+
+        // binary formats only contain the String of a V2 Version
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(VersionVisitor)
+        } else {
+            deserializer.deserialize_str(VersionVisitor)
+        }
+    }
+}
+
+fn invalid_version<E: de::Error>(found: impl fmt::Display) -> E {
+    E::custom(Error::InvalidVersion {
+        found: found.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::Value;
+
+    use crate::{Id, Notification, Request, Response, ResponseContent};
+
+    #[test]
+    fn test_version_const() {
+        assert_eq!(JSONRPC_VERSION, "2.0");
+        assert_eq!(
+            serde_json::to_value(Version::V2).unwrap(),
+            Value::from(JSONRPC_VERSION)
+        );
+        assert_eq!(
+            serde_json::from_value::<Version>(Value::from(JSONRPC_VERSION)).unwrap(),
+            Version::V2
+        );
+    }
+
+    #[test]
+    fn test_version_field() {
+        let request = Request::new("m", (), Some(1));
+        let notification = Notification::new("m", ());
+        let response = Response::<_, ()>::new(ResponseContent::Result(1), Some(Id::from(1)));
+
+        for (version, json) in [
+            (request.jsonrpc, serde_json::to_value(&request).unwrap()),
+            (
+                notification.jsonrpc,
+                serde_json::to_value(&notification).unwrap(),
+            ),
+            (response.jsonrpc, serde_json::to_value(&response).unwrap()),
+        ] {
+            assert_eq!(version, Version::V2);
+            assert_eq!(json["jsonrpc"], JSONRPC_VERSION);
+        }
+
+        assert!(
+            serde_json::from_str::<Notification<()>>(r#"{"jsonrpc":"1.0","method":"m"}"#).is_err()
+        );
+        assert!(serde_json::from_str::<Response<i32, ()>>(
+            r#"{"jsonrpc":"1.0","result":1,"id":1}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_version_invalid() {
+        for (json, found) in [
+            (r#""2""#, r#""2""#),
+            (r#""1.0""#, r#""1.0""#),
+            ("2", "2"),
+            ("2.0", "2.0"),
+            ("true", "true"),
+            ("null", "null"),
+        ] {
+            let error = serde_json::from_str::<Version>(json).err().unwrap();
+
+            assert!(error.to_string().starts_with(&format!(
+                r#"invalid jsonrpc version: expected "2.0", found {found}"#
+            )));
+        }
+
+        assert_eq!(
+            serde_json::from_str::<Version>(r#""2.0""#).unwrap(),
+            Version::V2
+        );
+
+        let json = r#"{"jsonrpc":null,"method":"m","id":1}"#;
+        assert!(serde_json::from_str::<Request<()>>(json).is_err());
+    }
+
+    #[test]
+    fn test_version_v1_serialize() {
+        let mut request = Request::new("m", [0], Some(1));
+        request.jsonrpc = Version::V1;
+
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"method":"m","params":[0],"id":1}"#
+        );
+
+        let mut response = Response::new(ResponseContent::<_, ()>::Result(1), Some(1));
+        response.jsonrpc = Version::V1;
+
+        assert_eq!(
+            serde_json::to_string(&response).unwrap(),
+            r#"{"result":1,"id":1}"#
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_version_v1_deserialize() {
+        let request: Request<Vec<i32>> =
+            serde_json::from_str(r#"{"method":"m","params":[],"id":1}"#).unwrap();
+        assert_eq!(request.jsonrpc, Version::V1);
+
+        let notification: Notification<Vec<i32>> =
+            serde_json::from_str(r#"{"method":"m","params":[]}"#).unwrap();
+        assert_eq!(notification.jsonrpc, Version::V1);
+
+        let response: Response<i32, ()> = serde_json::from_str(r#"{"result":1,"id":1}"#).unwrap();
+        assert_eq!(response.jsonrpc, Version::V1);
+
+        let request: Request<Vec<i32>> =
+            serde_json::from_str(r#"{"jsonrpc":"2.0","method":"m","params":[],"id":1}"#).unwrap();
+        assert_eq!(request.jsonrpc, Version::V2);
+    }
+
+    #[cfg(not(feature = "v1"))]
+    #[test]
+    fn test_version_missing() {
+        assert!(
+            serde_json::from_str::<Request<Vec<i32>>>(r#"{"method":"m","params":[],"id":1}"#)
+                .is_err()
+        );
+        assert!(
+            serde_json::from_str::<Notification<Vec<i32>>>(r#"{"method":"m","params":[]}"#)
+                .is_err()
+        );
+        assert!(serde_json::from_str::<Response<i32, ()>>(r#"{"result":1,"id":1}"#).is_err());
+    }
+}